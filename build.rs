@@ -0,0 +1,79 @@
+/// Generates the registry of solutions that are linked into the main binary when the `in_process` feature is enabled.
+/// Every `src/bin/NN.rs` and `src/bin/YYYY-NN.rs` file is included as a module and exposes the `SOLUTION` entry
+/// created by `solution!`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // bin names, e.g. `01` or `2022-01`.
+    let mut bins: Vec<String> = vec![];
+
+    // NOTE: only watch the solutions when they are linked, so editing a day does not rebuild everything.
+    if env::var("CARGO_FEATURE_IN_PROCESS").is_ok() {
+        println!("cargo:rerun-if-changed=src/bin");
+
+        bins = fs::read_dir(&bin_dir)
+            .unwrap()
+            .filter_map(|entry| {
+                let name = entry.ok()?.file_name().into_string().ok()?;
+                let bin = name.strip_suffix(".rs")?;
+                is_solution_bin(bin).then(|| bin.to_string())
+            })
+            .collect();
+
+        bins.sort_unstable();
+    }
+
+    let mut registry = String::from("// Generated by build.rs, do not edit.\n\n");
+
+    for bin in &bins {
+        let path = bin_dir.join(format!("{bin}.rs"));
+        registry.push_str(&format!(
+            "#[cfg(not(any(test, feature = \"dhat-heap\")))]\n#[allow(dead_code, unused_imports)]\n#[path = {:?}]\nmod {};\n\n",
+            path.to_string_lossy(),
+            module_name(bin)
+        ));
+    }
+
+    let entries: Vec<String> = bins
+        .iter()
+        .map(|bin| match bin.split_once('-') {
+            // NOTE: `solution!` derives the year from the binary name, which is not available once linked here.
+            Some((year, _)) => format!(
+                "advent_of_code::template::runner::Solution {{ year: advent_of_code::template::Year::new({year}), ..{}::SOLUTION }}",
+                module_name(bin)
+            ),
+            None => format!("{}::SOLUTION", module_name(bin)),
+        })
+        .collect();
+
+    registry.push_str(&format!(
+        "/// All solutions that can be run in-process.\n#[cfg(not(any(test, feature = \"dhat-heap\")))]\npub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[{}];\n\n",
+        entries.join(", ")
+    ));
+
+    registry.push_str(
+        "/// All solutions that can be run in-process.\n#[cfg(any(test, feature = \"dhat-heap\"))]\npub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[];\n",
+    );
+
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
+
+/// Whether a bin name belongs to a solution, i.e. `NN` or `YYYY-NN`.
+fn is_solution_bin(bin: &str) -> bool {
+    let is_number = |s: &str, len: usize| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+
+    match bin.split_once('-') {
+        Some((year, day)) => is_number(year, 4) && is_number(day, 2),
+        None => is_number(bin, 2),
+    }
+}
+
+fn module_name(bin: &str) -> String {
+    format!("day_{}", bin.replace('-', "_"))
+}
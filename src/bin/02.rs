@@ -35,7 +35,7 @@ fn parse_game(line: &str) -> Game {
 
     let id = game_string
         .split(' ')
-        .last()
+        .next_back()
         .unwrap()
         .parse::<u32>()
         .unwrap();
@@ -75,7 +75,7 @@ impl CategoryMap {
 
         category_map
             .value_maps_sorted
-            .sort_by(|a, b| a.source_range.start.cmp(&b.source_range.start));
+            .sort_by_key(|a| a.source_range.start);
 
         category_map
     }
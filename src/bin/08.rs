@@ -1,6 +1,8 @@
 use core::panic;
 use std::collections::HashMap;
 
+use advent_of_code::math;
+
 advent_of_code::solution!(8);
 
 pub fn part_one(input: &str) -> Option<u64> {
@@ -60,25 +62,11 @@ pub fn part_two(input: &str) -> Option<u64> {
                 *step_count == step_count_outer
             })
     }) {
-        let mut factor_map = HashMap::new();
-
-        for end_nodes in end_nodes_per_start_node {
-            let factors = get_prime_factorization(end_nodes[0].0);
-
-            for (factor, count) in factors {
-                let existing_count = factor_map.get(&factor).unwrap_or(&0);
-
-                if count > *existing_count {
-                    factor_map.insert(factor, count);
-                }
-            }
-        }
-
-        let mut result = 1;
-
-        for (factor, count) in factor_map {
-            result *= factor.pow(count);
-        }
+        let result = math::lcm_all(
+            end_nodes_per_start_node
+                .iter()
+                .map(|end_nodes| end_nodes[0].0),
+        );
 
         Some(result)
     } else {
@@ -138,49 +126,6 @@ fn get_starting_nodes<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<&'a str>
     nodes
 }
 
-fn get_prime_factorization(number: u64) -> Vec<(u64, u32)> {
-    let mut factors = Vec::new();
-    let mut current_number = number;
-
-    while current_number > 1 {
-        let mut has_found_factor = false;
-
-        for index in 2..current_number {
-            if current_number % index == 0 {
-                has_found_factor = true;
-
-                factors.push(index);
-
-                current_number /= index;
-
-                break;
-            }
-        }
-
-        if !has_found_factor {
-            factors.push(current_number);
-            break;
-        }
-    }
-
-    factors.sort();
-
-    let mut factors_summarized = Vec::new();
-
-    for factor in factors {
-        if let Some((_, count)) = factors_summarized
-            .iter_mut()
-            .find(|(inner_factor, _)| *inner_factor == factor)
-        {
-            *count += 1;
-        } else {
-            factors_summarized.push((factor, 1));
-        }
-    }
-
-    factors_summarized
-}
-
 struct Node<'a> {
     left: &'a str,
     right: &'a str,
@@ -80,7 +80,7 @@ struct Grid<'a> {
 }
 
 impl Grid<'_> {
-    fn from_input(input: &str) -> Grid {
+    fn from_input(input: &str) -> Grid<'_> {
         Grid {
             grid: input.lines().collect(),
         }
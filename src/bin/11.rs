@@ -19,7 +19,7 @@ struct GalaxyGrid<'a> {
 }
 
 impl GalaxyGrid<'_> {
-    fn from_input(input: &str) -> GalaxyGrid {
+    fn from_input(input: &str) -> GalaxyGrid<'_> {
         GalaxyGrid {
             grid: input.lines().collect(),
         }
@@ -90,17 +90,8 @@ impl Galaxy {
     }
 
     fn distance(&self, other: &Galaxy) -> usize {
-        let distance_x = if self.x < other.x {
-            other.x - self.x
-        } else {
-            self.x - other.x
-        };
-
-        let distance_y = if self.y < other.y {
-            other.y - self.y
-        } else {
-            self.y - other.y
-        };
+        let distance_x = other.x.abs_diff(self.x);
+        let distance_y = other.y.abs_diff(self.y);
 
         distance_x + distance_y
     }
@@ -103,7 +103,7 @@ struct RecordSlice<'a> {
 }
 
 impl RecordSlice<'_> {
-    fn from_record(record: &Record) -> RecordSlice {
+    fn from_record(record: &Record) -> RecordSlice<'_> {
         RecordSlice {
             sequence: &record.sequence,
             fill_counts: &record.fill_counts,
@@ -196,7 +196,7 @@ impl RecordSlice<'_> {
         fields_count - self.fill_counts.iter().sum::<usize>() - (fill_counts_count - 1)
     }
 
-    fn fill(&self, index: usize, fill_count: usize) -> Option<RecordSlice> {
+    fn fill(&self, index: usize, fill_count: usize) -> Option<RecordSlice<'_>> {
         let index_end = index + fill_count;
 
         if self.sequence[index..index_end]
@@ -217,7 +217,7 @@ impl RecordSlice<'_> {
         }
     }
 
-    fn next(&self, index: usize) -> RecordSlice {
+    fn next(&self, index: usize) -> RecordSlice<'_> {
         RecordSlice {
             sequence: &self.sequence[index..],
             fill_counts: &self.fill_counts[1..],
@@ -1,6 +1,8 @@
 use std::hash::{Hash, Hasher};
 use std::{collections::HashMap, vec};
 
+use advent_of_code::cycle;
+
 advent_of_code::solution!(14);
 
 pub fn part_one(input: &str) -> Option<usize> {
@@ -35,28 +37,17 @@ pub fn part_one(input: &str) -> Option<usize> {
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let mut platform = Platform::from_input(input);
-    let mut cycle_map = HashMap::new();
-
-    for index in 0..1000 {
-        platform.tilt_cycle();
-
-        if let Some(previous_index) = cycle_map.get(&platform) {
-            let cycle_length = index - previous_index;
-
-            let remaining_cycles = (1_000_000_000 - index - 1) % cycle_length;
-
-            for _ in 0..remaining_cycles {
-                platform.tilt_cycle();
-            }
-
-            return Some(platform.count_load());
-        } else {
-            cycle_map.insert(platform.clone(), index);
-        }
-    }
-
-    None
+    let platform = cycle::nth(
+        Platform::from_input(input),
+        |platform| {
+            let mut platform = platform.clone();
+            platform.tilt_cycle();
+            platform
+        },
+        1_000_000_000,
+    );
+
+    Some(platform.count_load())
 }
 
 #[derive(PartialEq, Eq, Clone)]
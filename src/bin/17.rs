@@ -3,37 +3,39 @@ use std::{
     collections::{BinaryHeap, HashMap, HashSet},
 };
 
-advent_of_code::solution!(17);
+advent_of_code::solution!(17, parse);
+
+fn parse(input: &str) -> Vec<Vec<usize>> {
+    input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|char| char.to_digit(10).unwrap() as usize)
+                .collect()
+        })
+        .collect()
+}
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let graph = Graph::from_input(input, 1, 3);
+pub fn part_one(grid: &[Vec<usize>]) -> Option<usize> {
+    let graph = Graph::new(grid, 1, 3);
 
     graph.smallest_path_cost_across()
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let graph = Graph::from_input(input, 4, 10);
+pub fn part_two(grid: &[Vec<usize>]) -> Option<usize> {
+    let graph = Graph::new(grid, 4, 10);
 
     graph.smallest_path_cost_across()
 }
 
-struct Graph {
-    grid: Vec<Vec<usize>>,
+struct Graph<'a> {
+    grid: &'a [Vec<usize>],
     same_direction_min: usize,
     same_direction_max: usize,
 }
 
-impl Graph {
-    fn from_input(input: &str, same_direction_min: usize, same_direction_max: usize) -> Graph {
-        let grid = input
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|char| char.to_digit(10).unwrap() as usize)
-                    .collect()
-            })
-            .collect();
-
+impl Graph<'_> {
+    fn new(grid: &[Vec<usize>], same_direction_min: usize, same_direction_max: usize) -> Graph<'_> {
         Graph {
             grid,
             same_direction_min,
@@ -97,17 +99,8 @@ impl Graph {
     }
 
     fn estimate_cost_between(&self, start: &Coordinate, end: &Coordinate) -> usize {
-        let x_diff = if start.x > end.x {
-            start.x - end.x
-        } else {
-            end.x - start.x
-        };
-
-        let y_diff = if start.y > end.y {
-            start.y - end.y
-        } else {
-            end.y - start.y
-        };
+        let x_diff = start.x.abs_diff(end.x);
+        let y_diff = start.y.abs_diff(end.y);
 
         x_diff + y_diff
     }
@@ -116,7 +109,7 @@ impl Graph {
         &'a self,
         node: &'a Node,
         end: &'a Coordinate,
-    ) -> impl Iterator<Item = Node> + '_ {
+    ) -> impl Iterator<Item = Node> + 'a {
         [
             Direction::Up,
             Direction::Down,
@@ -313,13 +306,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(102));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(94));
     }
 }
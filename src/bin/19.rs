@@ -1,18 +1,32 @@
 use std::{cmp::Ordering, collections::HashMap, ops::Range};
 
-advent_of_code::solution!(19);
+advent_of_code::solution!(19, parse);
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub struct Puzzle<'a> {
+    workflow_map: WorkflowMap<'a>,
+    objects: Vec<Object>,
+}
+
+fn parse(input: &str) -> Puzzle<'_> {
     let mut input_iterator = input.split("\n\n");
-    let workflow_map = WorkflowMap::from_input(input_iterator.next().unwrap());
 
-    let sum = input_iterator
-        .next()
-        .unwrap()
-        .lines()
-        .map(Object::from_line)
+    Puzzle {
+        workflow_map: WorkflowMap::from_input(input_iterator.next().unwrap()),
+        objects: input_iterator
+            .next()
+            .unwrap()
+            .lines()
+            .map(Object::from_line)
+            .collect(),
+    }
+}
+
+pub fn part_one(puzzle: &Puzzle) -> Option<u32> {
+    let sum = puzzle
+        .objects
+        .iter()
         .filter_map(|object| {
-            if workflow_map.accepts(&object) {
+            if puzzle.workflow_map.accepts(object) {
                 Some(object.x + object.m + object.a + object.s)
             } else {
                 None
@@ -23,8 +37,9 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(sum)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let sum = WorkflowMap::from_input(input.split("\n\n").next().unwrap())
+pub fn part_two(puzzle: &Puzzle) -> Option<u64> {
+    let sum = puzzle
+        .workflow_map
         .accepted_object_ranges()
         .iter()
         .map(|object_range| object_range.count())
@@ -38,7 +53,7 @@ struct WorkflowMap<'a> {
 }
 
 impl WorkflowMap<'_> {
-    fn from_input(input: &str) -> WorkflowMap {
+    fn from_input(input: &str) -> WorkflowMap<'_> {
         WorkflowMap {
             workflows: input
                 .lines()
@@ -62,11 +77,11 @@ impl WorkflowMap<'_> {
         }
     }
 
-    fn next_command(&self, workflow_name: &str, object: &Object) -> &Command {
+    fn next_command(&self, workflow_name: &str, object: &Object) -> &Command<'_> {
         self.workflow(workflow_name).next_command(object)
     }
 
-    fn workflow(&self, name: &str) -> &Workflow {
+    fn workflow(&self, name: &str) -> &Workflow<'_> {
         self.workflows.get(name).unwrap()
     }
 
@@ -120,7 +135,7 @@ struct Workflow<'a> {
 }
 
 impl Workflow<'_> {
-    fn from_line(line: &str) -> Workflow {
+    fn from_line(line: &str) -> Workflow<'_> {
         let mut line_iterator = line.split('{');
 
         let name = line_iterator.next().unwrap();
@@ -134,7 +149,7 @@ impl Workflow<'_> {
         // Optimization that removes unnecessary rules
         while rules
             .last()
-            .map_or(false, |rule| rule.command == final_command)
+            .is_some_and(|rule| rule.command == final_command)
         {
             rules.pop();
         }
@@ -146,7 +161,7 @@ impl Workflow<'_> {
         }
     }
 
-    fn next_command(&self, object: &Object) -> &Command {
+    fn next_command(&self, object: &Object) -> &Command<'_> {
         self.rules
             .iter()
             .find(|rule| rule.applies_to(object))
@@ -160,7 +175,7 @@ struct Rule<'a> {
 }
 
 impl Rule<'_> {
-    fn from_string(string: &str) -> Rule {
+    fn from_string(string: &str) -> Rule<'_> {
         let mut string_iterator = string.split(':');
 
         Rule {
@@ -223,7 +238,7 @@ enum Command<'a> {
 }
 
 impl Command<'_> {
-    fn from_string(string: &str) -> Command {
+    fn from_string(string: &str) -> Command<'_> {
         match string {
             "A" => Command::Accept,
             "R" => Command::Reject,
@@ -338,13 +353,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(19114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(167409079868000));
     }
 }
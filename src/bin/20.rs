@@ -1,5 +1,7 @@
 use std::collections::{HashMap, VecDeque};
 
+use advent_of_code::math;
+
 advent_of_code::solution!(20);
 
 pub fn part_one(input: &str) -> Option<u64> {
@@ -33,7 +35,7 @@ pub fn part_two(input: &str) -> Option<u64> {
         })
     }
 
-    Some(runtimes.values().product::<u64>())
+    Some(math::lcm_all(runtimes.values().copied()))
 }
 
 struct Program<'a> {
@@ -42,7 +44,7 @@ struct Program<'a> {
 }
 
 impl Program<'_> {
-    fn from_input(input: &str) -> Program {
+    fn from_input(input: &str) -> Program<'_> {
         let mut module_outputs = Vec::new();
         let mut modules = HashMap::new();
         let mut module_inputs: HashMap<&str, Vec<&str>> = HashMap::new();
@@ -101,7 +103,7 @@ struct Module<'a> {
 }
 
 impl<'a> Module<'a> {
-    fn from_line(line: &str) -> Module {
+    fn from_line(line: &str) -> Module<'_> {
         let mut line_iterator = line.split(" -> ");
         let name_part = line_iterator.next().unwrap();
         let output_modules = line_iterator.next().unwrap().split(", ").collect();
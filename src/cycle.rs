@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The cycle of a sequence of states `x0, x1 = step(x0), x2 = step(x1), ...` that eventually repeats.
+///
+/// ```
+/// # use advent_of_code::cycle::{brent, Cycle};
+/// // 0, 1, 2, 3, 4, 2, 3, 4, ...
+/// let cycle = brent(0, |&n| if n == 4 { 2 } else { n + 1 });
+/// assert_eq!(cycle, Cycle { start: 2, length: 3 });
+/// assert_eq!(cycle.equivalent_step(9), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps before the cycle starts, i.e. the index of the first repeated state.
+    pub start: usize,
+    /// The number of steps until a state of the cycle repeats.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Returns the smallest step that leads to the same state as step `n`.
+    #[must_use]
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// Returns the state after `n` steps by only simulating the steps up to [`Cycle::equivalent_step`].
+    pub fn nth<S>(&self, start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.equivalent_step(n)).fold(start, |state, _| step(&state))
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which needs little memory and fewer steps than [`floyd`].
+/// Never returns if the sequence does not repeat.
+pub fn brent<S: PartialEq + Clone>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // find the length by searching successive powers of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // a hare that is `length` steps ahead meets the tortoise at the start of the cycle.
+    let mut tortoise = start.clone();
+    let mut hare = (0..length).fold(start, |state, _| step(&state));
+    let mut cycle_start = 0;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+
+    Cycle {
+        start: cycle_start,
+        length,
+    }
+}
+
+/// Finds the cycle with Floyd's algorithm ("tortoise and hare"), which needs little memory.
+/// Never returns if the sequence does not repeat.
+pub fn floyd<S: PartialEq + Clone>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // the hare moves twice as fast, so they meet within the cycle.
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // moving at the same speed, they meet at the start of the cycle.
+    let mut tortoise = start;
+    let mut cycle_start = 0;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+
+    let mut hare = step(&tortoise);
+    let mut length = 1;
+
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle {
+        start: cycle_start,
+        length,
+    }
+}
+
+/// Finds the cycle by remembering every state, which needs the fewest steps.
+/// Use this if steps are expensive and states are small.
+/// Never returns if the sequence does not repeat.
+pub fn hashed<S: Eq + Hash + Clone>(start: S, step: impl FnMut(&S) -> S) -> Cycle {
+    find_with_history(start, step, None).0
+}
+
+/// Returns the state after `n` steps, skipping over repetitions once a cycle is found.
+/// Like [`hashed`], this remembers every state until then, so no step is simulated twice.
+///
+/// ```
+/// # use advent_of_code::cycle::nth;
+/// assert_eq!(nth(0, |&n| (n + 1) % 7, 1_000_000_000), 6);
+/// ```
+pub fn nth<S: Eq + Hash + Clone>(start: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    let (cycle, mut history) = find_with_history(start, step, Some(n));
+    history.swap_remove(cycle.equivalent_step(n))
+}
+
+/// Steps until a state repeats or, if set, until step `limit`, returns all states seen until then.
+/// If the limit is reached first, the returned cycle starts right after the limit, so every step up to
+/// the limit is its own [`Cycle::equivalent_step`]. Its length of `1` only keeps it a valid [`Cycle`].
+fn find_with_history<S: Eq + Hash + Clone>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    limit: Option<usize>,
+) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+    let mut history = vec![start];
+
+    loop {
+        let index = history.len();
+
+        if limit.is_some_and(|limit| index > limit) {
+            let cycle = Cycle {
+                start: index,
+                length: 1,
+            };
+            return (cycle, history);
+        }
+
+        let state = step(&history[index - 1]);
+
+        if let Some(&cycle_start) = seen.get(&state) {
+            let cycle = Cycle {
+                start: cycle_start,
+                length: index - cycle_start,
+            };
+            return (cycle, history);
+        }
+
+        seen.insert(state.clone(), index);
+        history.push(state);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{brent, find_with_history, floyd, hashed, nth, Cycle};
+
+    /// 0, 1, ..., 9, 4, 5, ...: a prefix of 4 steps and a cycle of 6 steps.
+    fn step(n: &u32) -> u32 {
+        if *n == 9 {
+            4
+        } else {
+            n + 1
+        }
+    }
+
+    const CYCLE: Cycle = Cycle {
+        start: 4,
+        length: 6,
+    };
+
+    #[test]
+    fn finds_cycles() {
+        assert_eq!(brent(0, step), CYCLE);
+        assert_eq!(floyd(0, step), CYCLE);
+        assert_eq!(hashed(0, step), CYCLE);
+    }
+
+    #[test]
+    fn finds_cycles_without_prefix() {
+        let expected = Cycle {
+            start: 0,
+            length: 6,
+        };
+        assert_eq!(brent(4, step), expected);
+        assert_eq!(floyd(4, step), expected);
+        assert_eq!(hashed(4, step), expected);
+
+        let fixed_point = Cycle {
+            start: 0,
+            length: 1,
+        };
+        assert_eq!(brent(1, |n| *n), fixed_point);
+        assert_eq!(floyd(1, |n| *n), fixed_point);
+        assert_eq!(hashed(1, |n| *n), fixed_point);
+    }
+
+    #[test]
+    fn fast_forwards() {
+        assert_eq!(CYCLE.equivalent_step(3), 3);
+        assert_eq!(CYCLE.equivalent_step(10), 4);
+        assert_eq!(CYCLE.equivalent_step(1_000_000_000), 4);
+
+        assert_eq!(CYCLE.nth(0, step, 1_000_000_001), 5);
+        assert_eq!(nth(0, step, 1_000_000_000), 4);
+        assert_eq!(nth(0, step, 2), 2);
+        assert_eq!(nth(0, step, 0), 0);
+    }
+
+    #[test]
+    fn fast_forwards_without_finding_a_cycle() {
+        // stops at step `n` for sequences that never repeat.
+        assert_eq!(nth(0u64, |n| n + 1, 1_000), 1_000);
+
+        let (cycle, history) = find_with_history(0u64, |n| n + 1, Some(5));
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 6,
+                length: 1
+            }
+        );
+        assert_eq!(history, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(cycle.equivalent_step(5), 5);
+    }
+}
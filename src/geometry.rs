@@ -0,0 +1,529 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point on an unbounded plane, with `y` growing downwards like the rows of an input.
+///
+/// # Display
+/// This value displays as `(x, y)`.
+///
+/// ```
+/// # use advent_of_code::geometry::{Direction, Point};
+/// let point = Point::new(2, 3) + Direction::Up;
+/// assert_eq!(point.to_string(), "(2, 2)");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    #[must_use]
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the sum of the absolute differences of the coordinates.
+    #[must_use]
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Iterates over the neighbours without diagonals, clockwise from the top.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    /// Iterates over the neighbours including diagonals, clockwise from the top.
+    pub fn neighbours_diagonal(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    /// Maps the point onto a `width` x `height` area that repeats infinitely in all directions.
+    ///
+    /// ```
+    /// # use advent_of_code::geometry::{Point, UPoint};
+    /// assert_eq!(Point::new(-1, 12).wrap(10, 10), UPoint::new(9, 2));
+    /// ```
+    #[must_use]
+    pub fn wrap(self, width: usize, height: usize) -> UPoint {
+        UPoint::new(
+            self.x.rem_euclid(width as i64) as usize,
+            self.y.rem_euclid(height as i64) as usize,
+        )
+    }
+
+    /// Returns which copy of a `width` x `height` area that repeats infinitely the point is in,
+    /// the original area being [`Point::ORIGIN`].
+    #[must_use]
+    pub fn tile(self, width: usize, height: usize) -> Self {
+        Self::new(
+            self.x.div_euclid(width as i64),
+            self.y.div_euclid(height as i64),
+        )
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<UPoint> for Point {
+    fn from(point: UPoint) -> Self {
+        Self::new(point.x as i64, point.y as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+}
+
+impl Add<Direction8> for Point {
+    type Output = Self;
+
+    fn add(self, direction: Direction8) -> Self {
+        self + direction.offset()
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A point that can not be negative, e.g. the position of a cell in a [`Grid`](crate::grid::Grid).
+///
+/// # Display
+/// This value displays as `(x, y)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UPoint {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl UPoint {
+    #[must_use]
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the sum of the absolute differences of the coordinates.
+    #[must_use]
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Moves one step in `direction`, returns [`None`] if that would be negative.
+    #[must_use]
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        self.checked_add_signed(direction.offset())
+    }
+
+    /// Adds a signed offset, returns [`None`] if the result would be negative.
+    #[must_use]
+    pub fn checked_add_signed(self, offset: Point) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(offset.x.try_into().ok()?)?,
+            self.y.checked_add_signed(offset.y.try_into().ok()?)?,
+        ))
+    }
+}
+
+impl Display for UPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(usize, usize)> for UPoint {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<UPoint> for (usize, usize) {
+    fn from(point: UPoint) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl TryFrom<Point> for UPoint {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Ok(Self::new(point.x.try_into()?, point.y.try_into()?))
+    }
+}
+
+impl Add for UPoint {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for UPoint {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for UPoint {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<usize> for UPoint {
+    type Output = Self;
+
+    fn mul(self, factor: usize) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the four directions along the axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise from the top.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Returns the direction after turning 90 degrees counter-clockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    /// Returns the direction after turning 90 degrees clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_horizontal(self) -> bool {
+        matches!(self, Self::Right | Self::Left)
+    }
+
+    /// Returns the offset of a step in this direction.
+    #[must_use]
+    pub const fn offset(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::Right => Point::new(1, 0),
+            Self::Down => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    /// Accepts `U`, `R`, `D` and `L`, `N`, `E`, `S` and `W` or arrows like `^`, `>`, `v` and `<`.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | 'N' | '^' => Ok(Self::Up),
+            'R' | 'E' | '>' => Ok(Self::Right),
+            'D' | 'S' | 'v' => Ok(Self::Down),
+            'L' | 'W' | '<' => Ok(Self::Left),
+            c => Err(ParseDirectionError(c)),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Direction`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseDirectionError(char);
+
+impl Error for ParseDirectionError {}
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expecting a direction like `U` or `^`, found `{}`",
+            self.0
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the eight directions along the axes and diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions, clockwise from the top.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Returns the direction after turning 45 degrees counter-clockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Returns the direction after turning 45 degrees clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    #[must_use]
+    pub const fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// Returns the offset of a step in this direction.
+    #[must_use]
+    pub const fn offset(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::UpRight => Point::new(1, -1),
+            Self::Right => Point::new(1, 0),
+            Self::DownRight => Point::new(1, 1),
+            Self::Down => Point::new(0, 1),
+            Self::DownLeft => Point::new(-1, 1),
+            Self::Left => Point::new(-1, 0),
+            Self::UpLeft => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self::Up,
+            Direction::Right => Self::Right,
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, Direction8, ParseDirectionError, Point, UPoint};
+
+    #[test]
+    fn does_arithmetic() {
+        let mut point = Point::new(1, 2);
+        assert_eq!(point + Point::new(3, -4), Point::new(4, -2));
+        assert_eq!(point - Point::new(3, -4), Point::new(-2, 6));
+        assert_eq!(point * 3, Point::new(3, 6));
+        assert_eq!(-point, Point::new(-1, -2));
+
+        point += Direction::Left;
+        point += Direction::Up;
+        assert_eq!(point, Point::new(0, 1));
+        assert_eq!(point + Direction8::DownRight, Point::new(1, 2));
+
+        assert_eq!(UPoint::new(1, 2) + UPoint::new(3, 4), UPoint::new(4, 6));
+        assert_eq!(UPoint::new(3, 4) - UPoint::new(1, 2), UPoint::new(2, 2));
+    }
+
+    #[test]
+    fn measures_distances() {
+        assert_eq!(Point::new(-1, 2).manhattan_distance(Point::new(3, -4)), 10);
+        assert_eq!(UPoint::new(5, 2).manhattan_distance(UPoint::new(1, 4)), 6);
+    }
+
+    #[test]
+    fn steps_without_going_negative() {
+        assert_eq!(UPoint::new(0, 1).checked_step(Direction::Left), None);
+        assert_eq!(
+            UPoint::new(0, 1).checked_step(Direction::Up),
+            Some(UPoint::new(0, 0))
+        );
+        assert_eq!(
+            UPoint::new(2, 2).checked_add_signed(Point::new(-2, 1)),
+            Some(UPoint::new(0, 3))
+        );
+        assert!(UPoint::try_from(Point::new(-1, 0)).is_err());
+        assert_eq!(UPoint::try_from(Point::new(1, 0)), Ok(UPoint::new(1, 0)));
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let neighbours: Vec<Point> = Point::ORIGIN.neighbours().collect();
+        assert_eq!(
+            neighbours,
+            vec![
+                Point::new(0, -1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(-1, 0)
+            ]
+        );
+        assert_eq!(Point::ORIGIN.neighbours_diagonal().count(), 8);
+    }
+
+    #[test]
+    fn wraps_points() {
+        assert_eq!(Point::new(-1, 12).wrap(10, 10), UPoint::new(9, 2));
+        assert_eq!(Point::new(3, 4).wrap(10, 10), UPoint::new(3, 4));
+        assert_eq!(Point::new(-1, 12).tile(10, 10), Point::new(-1, 1));
+        assert_eq!(Point::new(-10, 9).tile(10, 10), Point::new(-1, 0));
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Right.opposite(), Direction::Left);
+        assert!(Direction::Left.is_horizontal());
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(
+                direction.offset() + direction.opposite().offset(),
+                Point::ORIGIN
+            );
+        }
+
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::UpRight.opposite(), Direction8::DownLeft);
+        assert!(Direction8::DownRight.is_diagonal());
+        assert_eq!(Direction8::from(Direction::Down), Direction8::Down);
+
+        for direction in Direction8::ALL {
+            assert_eq!(
+                direction.offset() + direction.opposite().offset(),
+                Point::ORIGIN
+            );
+        }
+    }
+
+    #[test]
+    fn parses_directions() {
+        assert_eq!(Direction::try_from('U'), Ok(Direction::Up));
+        assert_eq!(Direction::try_from('>'), Ok(Direction::Right));
+        assert_eq!(Direction::try_from('S'), Ok(Direction::Down));
+        assert_eq!(Direction::try_from('x'), Err(ParseDirectionError('x')));
+    }
+}
@@ -0,0 +1,456 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::geometry::{Direction, Direction8, Point, UPoint};
+
+/// Position of a cell as `(x, y)`, with `(0, 0)` in the top left corner.
+pub type Position = (usize, usize);
+
+/// A dense, rectangular grid of cells, stored row by row.
+///
+/// # Display
+/// Cells display row by row, each row followed by a newline.
+///
+/// ```
+/// # use advent_of_code::grid::Grid;
+/// let grid: Grid<char> = "#.\n.#".parse().unwrap();
+/// assert_eq!(grid[(1, 0)], '.');
+/// assert_eq!(grid.to_string(), "#.\n.#\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of `width` x `height` cells with the value `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Creates a grid from cells in row order,
+    /// returns [`None`] if their count is not a multiple of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 {
+            return cells.is_empty().then_some(Self {
+                width,
+                height: 0,
+                cells,
+            });
+        }
+
+        cells.len().is_multiple_of(width).then(|| Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses a grid from text, mapping every character to a cell.
+    /// All lines must have the same length, trailing newlines are ignored.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, ParseGridError> {
+        let mut width = None;
+        let mut cells = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let found = cells.len() - len;
+
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(ParseGridError {
+                        line: y + 1,
+                        expected,
+                        found,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        let width = width.unwrap_or_default();
+
+        Ok(Self {
+            width,
+            height: cells.len().checked_div(width).unwrap_or_default(),
+            cells,
+        })
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `position` lies within the grid.
+    #[must_use]
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Returns the cell at `position`, or [`None`] if it's out of bounds.
+    #[must_use]
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    /// Returns the cell at `position` mutably, or [`None`] if it's out of bounds.
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    /// Like [`Grid::get`], but for points that may be negative, e.g. when walking off the grid.
+    #[must_use]
+    pub fn get_signed(&self, point: Point) -> Option<&T> {
+        self.get(UPoint::try_from(point).ok()?.into())
+    }
+
+    /// Returns the cell at `point` as if the grid repeated infinitely in all directions.
+    ///
+    /// # Panics
+    /// Panics if the grid is empty.
+    #[must_use]
+    pub fn get_wrapping(&self, point: Point) -> &T {
+        &self[point.wrap(self.width, self.height)]
+    }
+
+    /// Iterates over all positions in row order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterates over all cells with their positions in row order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Iterates mutably over all cells with their positions in row order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// Returns the row `y`, or [`None`] if it's out of bounds.
+    #[must_use]
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Iterates over the rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Iterates over the cells of column `x` from top to bottom.
+    /// The iterator is empty if `x` is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// Iterates over the columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterates over the in-bounds neighbours of `position` without diagonals, clockwise from the top.
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offsets(position, Direction::ALL.map(Direction::offset))
+    }
+
+    /// Iterates over the in-bounds neighbours of `position` including diagonals, clockwise from the top.
+    pub fn neighbours_diagonal(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offsets(position, Direction8::ALL.map(Direction8::offset))
+    }
+
+    /// Returns the position of the first cell in row order that matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Creates a grid of the same size by mapping every cell.
+    #[must_use]
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns, i.e. mirrors the grid along its main diagonal.
+    #[must_use]
+    pub fn transposed(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    #[must_use]
+    pub fn rotated_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearranged(height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    /// Rotates the grid by 90 degrees counter-clockwise.
+    #[must_use]
+    pub fn rotated_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearranged(self.height, width, |(x, y)| (width - 1 - y, x))
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.1 * self.width + position.0)
+    }
+
+    fn offsets<const N: usize>(
+        &self,
+        position: Position,
+        offsets: [Point; N],
+    ) -> impl Iterator<Item = Position> + '_ {
+        offsets.into_iter().filter_map(move |offset| {
+            let position = UPoint::from(position).checked_add_signed(offset)?.into();
+            self.contains(position).then_some(position)
+        })
+    }
+
+    /// Creates a grid of `width` x `height` cells, where `source` maps each new position to the old one.
+    fn rearranged(&self, width: usize, height: usize, source: impl Fn(Position) -> Position) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|position| self[source(position)].clone())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {position:?} is out of bounds for a grid of {}x{}",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!("position {position:?} is out of bounds for a grid of {width}x{height}"),
+        }
+    }
+}
+
+impl<T> Index<UPoint> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: UPoint) -> &T {
+        &self[Position::from(point)]
+    }
+}
+
+impl<T> IndexMut<UPoint> for Grid<T> {
+    fn index_mut(&mut self, point: UPoint) -> &mut T {
+        &mut self[Position::from(point)]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Grid<char> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, |c| c)
+    }
+}
+
+/// An error which can be returned when parsing a [`Grid`] with lines of different lengths.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseGridError {
+    /// The first line that does not match the length of the first line, starting at 1.
+    pub line: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Error for ParseGridError {}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expecting {} cells on line {}, found {}",
+            self.expected, self.line, self.found
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, ParseGridError};
+    use crate::geometry::{Point, UPoint};
+
+    const INPUT: &str = "abc\ndef\n";
+
+    fn grid() -> Grid<char> {
+        INPUT.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(digits[(0, 1)], 3);
+
+        assert_eq!(
+            "ab\nc".parse::<Grid<char>>(),
+            Err(ParseGridError {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!("".parse::<Grid<char>>().unwrap().height(), 0);
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let mut grid = grid();
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.get_signed(Point::new(-1, 0)), None);
+        assert_eq!(grid.get_signed(Point::new(1, 1)), Some(&'e'));
+        assert_eq!(grid[UPoint::new(2, 0)], 'c');
+        assert_eq!(*grid.get_wrapping(Point::new(-1, 3)), 'f');
+
+        *grid.get_mut((0, 0)).unwrap() = 'x';
+        grid[(1, 0)] = 'y';
+        assert_eq!(grid.row(0), Some(&['x', 'y', 'c'][..]));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn panics_when_indexing_out_of_bounds() {
+        let _ = grid()[(3, 0)];
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec!["abc".chars().collect::<Vec<_>>(), "def".chars().collect()]
+        );
+
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.column(3).count(), 0);
+
+        assert_eq!(grid.find(|c| *c == 'e'), Some((1, 1)));
+        assert_eq!(grid.iter().nth(3), Some(((0, 1), &'d')));
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours((1, 0)).collect::<Vec<_>>(),
+            vec![(2, 0), (1, 1), (0, 0)]
+        );
+        assert_eq!(
+            grid.neighbours_diagonal((1, 0)).collect::<Vec<_>>(),
+            vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let grid = grid();
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotated_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotated_counter_clockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotated_clockwise().rotated_counter_clockwise(), grid);
+        assert_eq!(grid.map(|c| c.is_ascii_uppercase()).width(), 3);
+    }
+
+    #[test]
+    fn creates_grids_from_cells() {
+        assert_eq!(Grid::from_cells(2, vec![1, 2, 3]), None);
+        assert_eq!(Grid::from_cells(2, vec![1, 2, 3, 4]).unwrap().height(), 2);
+        assert_eq!(Grid::new(2, 3, 0).to_string(), "00\n00\n00\n");
+    }
+}
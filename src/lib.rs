@@ -1,3 +1,8 @@
+pub mod cycle;
+pub mod geometry;
+pub mod grid;
+pub mod math;
+pub mod search;
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
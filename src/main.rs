@@ -1,76 +1,159 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
-use args::{parse, AppArguments};
+use advent_of_code::template::commands::{
+    all, download, examples, read, scaffold, solve, time, verify,
+};
+use advent_of_code::template::run_multi::MultiRunOptions;
+use advent_of_code::template::runner::Solution;
+use advent_of_code::template::{Day, Year};
+use args::{parse, AppArguments, Arguments};
+use std::time::Duration;
 
-#[cfg(feature = "today")]
-use advent_of_code::template::Day;
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        commands::{solve::SolveOptions, time},
+        runner::InputSource,
+        Day, Year,
+    };
+    use std::{path::PathBuf, process, time::Duration};
+
+    pub struct Arguments {
+        /// Year passed via `--year`. Selects the year layout, see `template::paths`.
+        pub year: Option<Year>,
+        pub command: AppArguments,
+    }
 
     pub enum AppArguments {
         Download {
             day: Day,
         },
+        Examples {
+            day: Day,
+            block: Option<usize>,
+            part_two_block: Option<usize>,
+            force: bool,
+        },
         Read {
             day: Day,
+            refresh: bool,
         },
         Scaffold {
             day: Day,
             download: bool,
+            template: Option<String>,
+            answer_type: Option<String>,
         },
         Solve {
             day: Day,
-            release: bool,
-            dhat: bool,
-            submit: Option<u8>,
+            options: SolveOptions,
         },
         All {
             release: bool,
+            isolated: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            compare: Option<f64>,
+            isolated: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
+        },
+        Verify {
+            day: Option<Day>,
+            release: bool,
+            accept: bool,
+            isolated: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    pub fn parse() -> Result<Arguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        let year = args.opt_value_from_str("--year")?;
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                isolated: args.contains("--isolated"),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                timeout: parse_timeout(&mut args)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let compare = args.contains("--compare");
+                let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+                let isolated = args.contains("--isolated");
+                let jobs = args.opt_value_from_str("--jobs")?.unwrap_or(1);
+                let timeout = parse_timeout(&mut args)?;
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    compare: (compare || threshold.is_some())
+                        .then(|| threshold.unwrap_or(time::DEFAULT_REGRESSION_THRESHOLD)),
+                    isolated,
+                    jobs,
+                    timeout,
+                }
+            }
+            Some("verify") => {
+                let release = args.contains("--release");
+                let accept = args.contains("--accept");
+                let isolated = args.contains("--isolated");
+                let jobs = args.opt_value_from_str("--jobs")?.unwrap_or(1);
+                let timeout = parse_timeout(&mut args)?;
+
+                AppArguments::Verify {
+                    day: args.opt_free_from_str()?,
+                    release,
+                    accept,
+                    isolated,
+                    jobs,
+                    timeout,
                 }
             }
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
+            Some("examples") => AppArguments::Examples {
+                day: args.free_from_str()?,
+                block: args.opt_value_from_str("--block")?,
+                part_two_block: args.opt_value_from_str("--part-two-block")?,
+                force: args.contains("--force"),
+            },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
+                refresh: args.contains("--refresh"),
             },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
+                template: args.opt_value_from_str("--template")?,
+                answer_type: args.opt_value_from_str("--answer-type")?,
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+                options: SolveOptions {
+                    release: args.contains("--release"),
+                    submit_part: args.opt_value_from_str("--submit")?,
+                    dhat: args.contains("--dhat"),
+                    timeout: parse_timeout(&mut args)?,
+                    watch: args.contains("--watch"),
+                    test: args.contains("--test"),
+                    input: parse_input(&mut args)?,
+                },
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -89,7 +172,86 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok(Arguments {
+            year,
+            command: app_args,
+        })
+    }
+
+    /// Parses `--example [part]`, `--stdin` and `--input <path>` into the source a solution reads its input from.
+    fn parse_input(args: &mut pico_args::Arguments) -> Result<InputSource, pico_args::Error> {
+        if args.contains("--example") {
+            // NOTE: the optional part is a free argument, so this has to run after all other options are parsed.
+            return Ok(InputSource::Example(args.opt_free_from_str()?));
+        }
+
+        if args.contains("--stdin") {
+            return Ok(InputSource::Stdin);
+        }
+
+        Ok(args
+            .opt_value_from_str::<_, PathBuf>("--input")?
+            .map_or(InputSource::Puzzle, InputSource::File))
+    }
+
+    /// Parses `--timeout <seconds>` into a duration.
+    fn parse_timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, pico_args::Error> {
+        args.opt_value_from_fn("--timeout", |s| {
+            s.parse::<f64>()
+                .map_err(|e| e.to_string())
+                .and_then(|secs| Duration::try_from_secs_f64(secs).map_err(|e| e.to_string()))
+        })
+    }
+}
+
+/// Solutions linked into this binary, see `build.rs`.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+/// Returns the solutions to run in-process. Empty if the user asked for isolated child processes.
+fn get_solutions(isolated: bool) -> &'static [Solution] {
+    if isolated {
+        &[]
+    } else {
+        solutions::SOLUTIONS
+    }
+}
+
+fn get_multi_run_options(
+    year: Option<Year>,
+    is_release: bool,
+    isolated: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> MultiRunOptions<'static> {
+    MultiRunOptions {
+        year,
+        is_release,
+        is_timed: false,
+        jobs,
+        timeout,
+        solutions: get_solutions(isolated),
+    }
+}
+
+/// Downloads a day before scaffolding it, so the template can use the title of the puzzle.
+/// A failed download, e.g. because the puzzle is not unlocked yet, does not stop the scaffolding.
+fn download_before_scaffold(year: Option<Year>, day: Day) -> bool {
+    match download::download(year, day) {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("{e}");
+            let year_arg = year
+                .map(|year| format!(" --year {year}"))
+                .unwrap_or_default();
+            eprintln!(
+                "Scaffolding without the puzzle, run `cargo download {day}{year_arg}` later."
+            );
+            false
+        }
     }
 }
 
@@ -99,30 +261,83 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day, download } => {
-                scaffold::handle(day);
+        Ok(Arguments { year, command }) => match command {
+            AppArguments::All {
+                release,
+                isolated,
+                jobs,
+                timeout,
+            } => all::handle(get_multi_run_options(
+                year, release, isolated, jobs, timeout,
+            )),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                compare,
+                isolated,
+                jobs,
+                timeout,
+            } => time::handle(
+                day,
+                all,
+                store,
+                compare,
+                get_multi_run_options(year, true, isolated, jobs, timeout),
+            ),
+            AppArguments::Verify {
+                day,
+                release,
+                accept,
+                isolated,
+                jobs,
+                timeout,
+            } => verify::handle(
+                day,
+                accept,
+                get_multi_run_options(year, release, isolated, jobs, timeout),
+            ),
+            AppArguments::Download { day } => download::handle(year, day),
+            AppArguments::Examples {
+                day,
+                block,
+                part_two_block,
+                force,
+            } => examples::handle(year, day, block, part_two_block, force),
+            AppArguments::Read { day, refresh } => read::handle(year, day, refresh),
+            AppArguments::Scaffold {
+                day,
+                download,
+                template,
+                answer_type,
+            } => {
                 if download {
-                    download::handle(day);
+                    download_before_scaffold(year, day);
                 }
+                scaffold::handle(
+                    year,
+                    day,
+                    template.as_deref().unwrap_or(scaffold::DEFAULT_TEMPLATE),
+                    answer_type
+                        .as_deref()
+                        .unwrap_or(scaffold::DEFAULT_ANSWER_TYPE),
+                );
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
+            AppArguments::Solve { day, options } => solve::handle(year, day, &options),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day);
-                        download::handle(day);
-                        read::handle(day)
+                        let downloaded = download_before_scaffold(year, day);
+                        scaffold::handle(
+                            year,
+                            day,
+                            scaffold::DEFAULT_TEMPLATE,
+                            scaffold::DEFAULT_ANSWER_TYPE,
+                        );
+                        if downloaded {
+                            read::handle(year, day, false);
+                        }
                     }
                     None => {
                         eprintln!(
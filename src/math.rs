@@ -0,0 +1,322 @@
+use std::error::Error;
+use std::fmt::Display;
+
+/// Returns the greatest common divisor of `a` and `b`, `gcd(0, 0)` is `0`.
+#[must_use]
+pub const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Returns the least common multiple of `a` and `b`, which is `0` if either is `0`.
+///
+/// # Panics
+/// Panics if the result does not fit into an [`u64`], see [`checked_lcm`].
+#[must_use]
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).expect("least common multiple overflows u64")
+}
+
+/// Like [`lcm`], but returns [`None`] if the result does not fit into an [`u64`].
+#[must_use]
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Returns the greatest common divisor of all numbers, `0` if there are none.
+pub fn gcd_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+/// Returns the least common multiple of all numbers, `1` if there are none.
+/// Useful for finding the step at which several periodic events line up.
+///
+/// ```
+/// # use advent_of_code::math::lcm_all;
+/// assert_eq!(lcm_all([4, 6, 10]), 60);
+/// ```
+///
+/// # Panics
+/// Panics if the result does not fit into an [`u64`], see [`checked_lcm_all`].
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(1, lcm)
+}
+
+/// Like [`lcm_all`], but returns [`None`] if the result does not fit into an [`u64`].
+pub fn checked_lcm_all(numbers: impl IntoIterator<Item = u64>) -> Option<u64> {
+    numbers.into_iter().try_fold(1, checked_lcm)
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y = g`, where `g` is the (non-negative)
+/// greatest common divisor of `a` and `b`.
+#[must_use]
+pub const fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Returns `x` in `0..modulus` such that `a * x ≡ 1 (mod modulus)`,
+/// or [`None`] if `a` and `modulus` are not coprime or `modulus` is not positive.
+///
+/// ```
+/// # use advent_of_code::math::mod_inverse;
+/// assert_eq!(mod_inverse(3, 7), Some(5));
+/// assert_eq!(mod_inverse(2, 4), None);
+/// ```
+#[must_use]
+pub const fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+
+    match extended_gcd(a.rem_euclid(modulus), modulus) {
+        (1, x, _) => Some(x.rem_euclid(modulus)),
+        _ => None,
+    }
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` given as `(residue, modulus)` pairs
+/// with the Chinese Remainder Theorem. The moduli don't need to be coprime.
+///
+/// Returns `(x, m)` with the smallest solution `x` in `0..m`, where `m` is the least common
+/// multiple of the moduli, i.e. all solutions are `x + k * m`.
+/// Returns [`None`] if the congruences contradict each other.
+///
+/// ```
+/// # use advent_of_code::math::crt;
+/// // x ≡ 2 (mod 3), x ≡ 3 (mod 5) and x ≡ 2 (mod 7)
+/// assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// // x ≡ 1 (mod 4) and x ≡ 2 (mod 6) has no solution, both moduli are even.
+/// assert_eq!(crt([(1, 4), (2, 6)]), None);
+/// ```
+///
+/// # Panics
+/// Panics if a modulus is not positive or the least common multiple of the moduli does not
+/// fit into an [`i64`], see [`checked_crt`].
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    match checked_crt(congruences) {
+        Ok(solution) => Some(solution),
+        Err(CrtError::NoSolution) => None,
+        Err(e) => panic!("{e}"),
+    }
+}
+
+/// Like [`crt`], but returns an error instead of panicking.
+pub fn checked_crt(
+    congruences: impl IntoIterator<Item = (i64, i64)>,
+) -> Result<(i64, i64), CrtError> {
+    // intermediate products fit into an i128 as long as the moduli fit into an i64.
+    let mut solution: i128 = 0;
+    let mut modulus: i128 = 1;
+
+    for (residue, next_modulus) in congruences {
+        if next_modulus <= 0 {
+            return Err(CrtError::InvalidModulus(next_modulus));
+        }
+
+        let next_modulus = i128::from(next_modulus);
+        let (g, x, _) = extended_gcd((modulus % next_modulus) as i64, next_modulus as i64);
+        let (g, x) = (i128::from(g), i128::from(x));
+        let difference = i128::from(residue) - solution;
+
+        if difference % g != 0 {
+            return Err(CrtError::NoSolution);
+        }
+
+        // solve `solution + modulus * k ≡ residue (mod next_modulus)` for k.
+        let step = next_modulus / g;
+        let k = (difference / g % step * x).rem_euclid(step);
+
+        solution += modulus * k;
+        modulus *= step;
+
+        if modulus > i128::from(i64::MAX) {
+            return Err(CrtError::Overflow);
+        }
+
+        solution = solution.rem_euclid(modulus);
+    }
+
+    Ok((solution as i64, modulus as i64))
+}
+
+/// An error which can be returned when solving congruences with [`checked_crt`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum CrtError {
+    /// The congruences contradict each other.
+    NoSolution,
+    /// A modulus is zero or negative.
+    InvalidModulus(i64),
+    /// The least common multiple of the moduli does not fit into an [`i64`].
+    Overflow,
+}
+
+impl Error for CrtError {}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrtError::NoSolution => f.write_str("the congruences have no common solution"),
+            CrtError::InvalidModulus(modulus) => {
+                write!(f, "expecting positive moduli, found {modulus}")
+            }
+            CrtError::Overflow => {
+                f.write_str("the least common multiple of the moduli overflows i64")
+            }
+        }
+    }
+}
+
+/// Returns the prime factors of `number` with their exponents, in ascending order.
+/// Uses trial division, which is fast enough for numbers up to about `10^14`.
+///
+/// ```
+/// # use advent_of_code::math::prime_factors;
+/// assert_eq!(prime_factors(360), vec![(2, 3), (3, 2), (5, 1)]);
+/// ```
+#[must_use]
+pub fn prime_factors(mut number: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+    let mut divisor = 2;
+
+    while divisor <= number / divisor {
+        let mut exponent = 0;
+
+        while number.is_multiple_of(divisor) {
+            number /= divisor;
+            exponent += 1;
+        }
+
+        if exponent > 0 {
+            factors.push((divisor, exponent));
+        }
+
+        divisor += if divisor == 2 { 1 } else { 2 };
+    }
+
+    if number > 1 {
+        factors.push((number, 1));
+    }
+
+    factors
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        checked_crt, checked_lcm, checked_lcm_all, crt, extended_gcd, gcd, gcd_all, lcm, lcm_all,
+        mod_inverse, prime_factors, CrtError,
+    };
+
+    #[test]
+    fn finds_divisors_and_multiples() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(gcd_all([]), 0);
+        assert_eq!(lcm_all([4, 6, 10]), 60);
+        assert_eq!(lcm_all([]), 1);
+    }
+
+    #[test]
+    fn checks_for_overflows() {
+        assert_eq!(checked_lcm(u64::MAX, 2), None);
+        assert_eq!(checked_lcm(u64::MAX, 3), Some(u64::MAX));
+        assert_eq!(checked_lcm_all([1 << 40, 3 << 30, 5 << 20]), Some(15 << 40));
+        assert_eq!(
+            checked_lcm_all([1 << 40, 3, 5, 7, 11, 13, 17, 19, 23, 29]),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn panics_on_overflows() {
+        let _ = lcm(u64::MAX, 2);
+    }
+
+    #[test]
+    fn solves_bezout_identities() {
+        for (a, b) in [(240, 46), (46, 240), (-240, 46), (17, 0), (0, -17), (7, 13)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn finds_inverses() {
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(2, 4), None);
+        assert_eq!(mod_inverse(2, 0), None);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(-1, 3), (8, 5)]), Some((8, 15)));
+        assert_eq!(crt([]), Some((0, 1)));
+
+        // moduli that are not coprime.
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(0, 10), (0, 15), (0, 6)]), Some((0, 30)));
+
+        // periods that start with an offset, e.g. events at 5 + 14k and 12 + 21k.
+        assert_eq!(crt([(5, 14), (12, 21)]), Some((33, 42)));
+    }
+
+    #[test]
+    fn checks_congruences() {
+        assert_eq!(checked_crt([(1, 4), (2, 6)]), Err(CrtError::NoSolution));
+        assert_eq!(checked_crt([(1, 0)]), Err(CrtError::InvalidModulus(0)));
+        assert_eq!(
+            checked_crt([(0, i64::MAX), (1, i64::MAX - 1)]),
+            Err(CrtError::Overflow)
+        );
+
+        // large moduli whose product still fits.
+        let (a, b) = (1 << 31, (1 << 31) - 1);
+        let (x, m) = checked_crt([(1, a), (2, b)]).unwrap();
+        assert_eq!((x % a, x % b, m), (1, 2, a * b));
+    }
+
+    #[test]
+    fn factorizes_numbers() {
+        assert_eq!(prime_factors(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(prime_factors(97), vec![(97, 1)]);
+        assert_eq!(prime_factors(1), vec![]);
+        assert_eq!(prime_factors(0), vec![]);
+        assert_eq!(
+            prime_factors(600_851_475_143),
+            vec![(71, 1), (839, 1), (1471, 1), (6857, 1)]
+        );
+    }
+}
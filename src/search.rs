@@ -0,0 +1,351 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cheapest path found by a search, from the start state to a goal state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// The sum of the costs of all steps.
+    pub cost: C,
+    /// All states of the path, including the start and the goal.
+    pub states: Vec<S>,
+}
+
+/// Returns the number of steps to every state reachable from `start`.
+///
+/// ```
+/// # use advent_of_code::search::bfs_distances;
+/// let distances = bfs_distances(0, |&n| [n + 1, n + 2].into_iter().filter(|&n| n <= 4));
+/// assert_eq!(distances[&4], 2);
+/// ```
+pub fn bfs_distances<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Eq + Hash + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state] + 1;
+
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Returns a path with the fewest steps from `start` to a state that matches `is_goal`,
+/// or [`None`] if no such state is reachable.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Eq + Hash + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut states = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&states.state(index)) {
+            return Some(states.path(index));
+        }
+
+        let distance = states.cost(index) + 1;
+
+        for next in successors(&states.state(index)) {
+            if let Some(next) = states.insert_new(next, index, distance) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the cost of the cheapest path to every state reachable from `start`.
+/// `successors` returns the states reachable in one step along with the cost of that step.
+pub fn dijkstra_costs<S, C, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, C>
+where
+    S: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut states = Visited::new(start, C::default());
+    let mut queue = BinaryHeap::from([Reverse((C::default(), 0))]);
+
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        // states are queued again when a cheaper path to them is found, skip the outdated entries.
+        if cost > states.cost(index) {
+            continue;
+        }
+
+        for (next, step_cost) in successors(&states.state(index)) {
+            if let Some(next) = states.insert_cheaper(next, index, cost + step_cost) {
+                queue.push(Reverse((cost + step_cost, next)));
+            }
+        }
+    }
+
+    states.into_costs()
+}
+
+/// Returns the cheapest path from `start` to a state that matches `is_goal`,
+/// or [`None`] if no such state is reachable.
+/// `successors` returns the states reachable in one step along with the cost of that step.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but visits states in order of their cost plus the estimate of `heuristic`,
+/// which is usually faster. The path is only guaranteed to be the cheapest one if `heuristic`
+/// never overestimates the remaining cost to a goal, e.g. the Manhattan distance on a grid.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let estimate = heuristic(&start);
+    let mut states = Visited::new(start, C::default());
+    let mut queue = BinaryHeap::from([Reverse((estimate, C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // states are queued again when a cheaper path to them is found, skip the outdated entries.
+        if cost > states.cost(index) {
+            continue;
+        }
+
+        if is_goal(&states.state(index)) {
+            return Some(states.path(index));
+        }
+
+        for (next, step_cost) in successors(&states.state(index)) {
+            let next_cost = cost + step_cost;
+            let estimate = next_cost + heuristic(&next);
+
+            if let Some(next) = states.insert_cheaper(next, index, next_cost) {
+                queue.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The states seen by a search, with the cheapest known cost and the state it was reached from.
+/// States are referred to by index, so they don't need to implement [`Ord`] for the queues.
+struct Visited<S, C> {
+    indices: HashMap<S, usize>,
+    states: Vec<(S, C, Option<usize>)>,
+}
+
+impl<S: Eq + Hash + Clone, C: Copy + Ord> Visited<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        Self {
+            indices: HashMap::from([(start.clone(), 0)]),
+            states: vec![(start, cost, None)],
+        }
+    }
+
+    fn state(&self, index: usize) -> S {
+        self.states[index].0.clone()
+    }
+
+    fn cost(&self, index: usize) -> C {
+        self.states[index].1
+    }
+
+    /// Adds `state` if it was not seen before, returns its index if it was added.
+    fn insert_new(&mut self, state: S, parent: usize, cost: C) -> Option<usize> {
+        if self.indices.contains_key(&state) {
+            return None;
+        }
+
+        Some(self.push(state, parent, cost))
+    }
+
+    /// Adds `state` or updates it if `cost` is cheaper than its known cost,
+    /// returns its index if it was added or updated.
+    fn insert_cheaper(&mut self, state: S, parent: usize, cost: C) -> Option<usize> {
+        match self.indices.get(&state) {
+            Some(&index) if self.states[index].1 <= cost => None,
+            Some(&index) => {
+                self.states[index].1 = cost;
+                self.states[index].2 = Some(parent);
+                Some(index)
+            }
+            None => Some(self.push(state, parent, cost)),
+        }
+    }
+
+    fn push(&mut self, state: S, parent: usize, cost: C) -> usize {
+        let index = self.states.len();
+        self.indices.insert(state.clone(), index);
+        self.states.push((state, cost, Some(parent)));
+        index
+    }
+
+    /// Follows the parents of the state at `index` back to the start.
+    fn path(&self, index: usize) -> Path<S, C> {
+        let mut states = vec![];
+        let mut current = Some(index);
+
+        while let Some(index) = current {
+            states.push(self.states[index].0.clone());
+            current = self.states[index].2;
+        }
+
+        states.reverse();
+
+        Path {
+            cost: self.cost(index),
+            states,
+        }
+    }
+
+    fn into_costs(self) -> HashMap<S, C> {
+        self.states
+            .into_iter()
+            .map(|(state, cost, _)| (state, cost))
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, bfs_distances, dijkstra, dijkstra_costs, Path};
+    use crate::geometry::UPoint;
+    use crate::grid::Grid;
+
+    const MAZE: &str = "S.#.\n..#.\n...E\n";
+
+    fn open_neighbours(grid: &Grid<char>, point: UPoint) -> Vec<UPoint> {
+        grid.neighbours(point.into())
+            .filter(|&position| grid[position] != '#')
+            .map(UPoint::from)
+            .collect()
+    }
+
+    #[test]
+    fn finds_distances_with_bfs() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let distances = bfs_distances(UPoint::new(0, 0), |&p| open_neighbours(&grid, p));
+
+        assert_eq!(distances.len(), 10);
+        assert_eq!(distances[&UPoint::new(0, 0)], 0);
+        assert_eq!(distances[&UPoint::new(3, 0)], 7);
+        assert_eq!(distances[&UPoint::new(3, 2)], 5);
+    }
+
+    #[test]
+    fn finds_shortest_paths_with_bfs() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let end = UPoint::new(3, 2);
+        let path = bfs(
+            UPoint::new(0, 0),
+            |&p| open_neighbours(&grid, p),
+            |&p| p == end,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states.len(), 6);
+        assert_eq!(path.states.first(), Some(&UPoint::new(0, 0)));
+        assert_eq!(path.states.last(), Some(&end));
+
+        assert_eq!(bfs(0, |_| [], |&n| n == 1), None);
+        assert_eq!(
+            bfs(0, |_| [], |&n| n == 0),
+            Some(Path {
+                cost: 0,
+                states: vec![0]
+            })
+        );
+    }
+
+    /// A graph where the direct edge is more expensive than the detour.
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn finds_cheapest_paths_with_dijkstra() {
+        let path = dijkstra('a', edges, |&n| n == 'd').unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states, vec!['a', 'b', 'c', 'd']);
+
+        assert_eq!(dijkstra('d', edges, |&n| n == 'a'), None);
+
+        let costs = dijkstra_costs('a', edges);
+        assert_eq!(costs.len(), 4);
+        assert_eq!(costs[&'c'], 3);
+        assert_eq!(costs[&'d'], 6);
+    }
+
+    #[test]
+    fn finds_cheapest_paths_with_astar() {
+        let grid = Grid::parse("1163\n1381\n2136\n", |c| c.to_digit(10).unwrap()).unwrap();
+        let end = UPoint::new(3, 2);
+
+        let path = astar(
+            UPoint::new(0, 0),
+            |&p| {
+                grid.neighbours(p.into())
+                    .map(|position| (UPoint::from(position), grid[position]))
+                    .collect::<Vec<_>>()
+            },
+            |&p| p.manhattan_distance(end) as u32,
+            |&p| p == end,
+        )
+        .unwrap();
+
+        let expected = dijkstra(
+            UPoint::new(0, 0),
+            |&p| {
+                grid.neighbours(p.into())
+                    .map(|position| (UPoint::from(position), grid[position]))
+                    .collect::<Vec<_>>()
+            },
+            |&p| p == end,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, expected.cost);
+        assert_eq!(path.cost, 13);
+        assert_eq!(path.states.last(), Some(&end));
+    }
+}
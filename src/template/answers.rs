@@ -0,0 +1,318 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{paths, Day, Year};
+
+/// Represents the accepted answers for a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Result of comparing an answer against the stored, accepted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches the accepted answer.
+    Pass,
+    /// The answer differs from the accepted answer, or no answer was returned.
+    Fail { expected: String },
+    /// There is no accepted answer stored for this part.
+    Missing,
+}
+
+/// Represents accepted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self, year: Option<Year>) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(paths::answers_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file(year: Option<Year>) -> Self {
+        let s = match fs::read_to_string(paths::answers_path(year)) {
+            Err(e) if e.kind() == ErrorKind::NotFound => return Answers::default(),
+            s => s.map_err(|x| x.to_string()).and_then(Answers::try_from),
+        };
+
+        match s {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{e}");
+                Answers::default()
+            }
+        }
+    }
+
+    /// Returns the accepted answer for a part, if any.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.day == day)?;
+
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Records the accepted answer for a part, overwriting a previous one.
+    pub fn set(&mut self, day: Day, part: u8, value: &str) {
+        let index = if let Some(index) = self.data.iter().position(|a| a.day == day) {
+            index
+        } else {
+            self.data.push(Answer {
+                day,
+                part_1: None,
+                part_2: None,
+            });
+            self.data.sort_unstable_by_key(|answer| answer.day);
+            self.data.iter().position(|a| a.day == day).unwrap()
+        };
+
+        let answer = &mut self.data[index];
+
+        match part {
+            1 => answer.part_1 = Some(value.into()),
+            2 => answer.part_2 = Some(value.into()),
+            _ => {}
+        }
+    }
+
+    /// Compares an answer against the accepted answer of a part.
+    pub fn verify(&self, day: Day, part: u8, answer: Option<&str>) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Missing,
+            Some(expected) if Some(expected) == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.into(),
+            },
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        map.insert(
+            "part_1".into(),
+            value
+                .part_1
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "part_2".into(),
+            value
+                .part_2
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(Answer {
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{Answer, Answers};
+
+    fn get_mock_answers() -> Answers {
+        Answers {
+            data: vec![
+                Answer {
+                    day: day!(1),
+                    part_1: Some("142".into()),
+                    part_2: Some("281".into()),
+                },
+                Answer {
+                    day: day!(3),
+                    part_1: Some("4361".into()),
+                    part_2: None,
+                },
+            ],
+        }
+    }
+
+    mod deserialization {
+        use crate::{day, template::answers::Answers};
+
+        #[test]
+        fn handles_json_answers() {
+            let json =
+                r#"{ "data": [{ "day": "01", "part_1": "42", "part_2": null }] }"#.to_string();
+            let answers = Answers::try_from(json).unwrap();
+            assert_eq!(answers.data.len(), 1);
+            let answer = answers.data.first().unwrap();
+            assert_eq!(answer.day, day!(1));
+            assert_eq!(answer.part_1, Some("42".to_string()));
+            assert_eq!(answer.part_2, None);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_answers() {
+            let json = r#"{ "data": [{ "day": "01" }] }"#.to_string();
+            Answers::try_from(json).unwrap();
+        }
+    }
+
+    mod serialization {
+        use super::get_mock_answers;
+        use crate::template::answers::Answers;
+        use tinyjson::JsonValue;
+
+        #[test]
+        fn roundtrips_answers() {
+            let answers = get_mock_answers();
+            let json = JsonValue::from(answers.clone()).stringify().unwrap();
+            assert_eq!(Answers::try_from(json).unwrap().data, answers.data);
+        }
+    }
+
+    mod set {
+        use super::get_mock_answers;
+        use crate::day;
+
+        #[test]
+        fn inserts_new_days_sorted() {
+            let mut answers = get_mock_answers();
+            answers.set(day!(2), 2, "8");
+            assert_eq!(answers.data.len(), 3);
+            assert_eq!(answers.data[1].day, day!(2));
+            assert_eq!(answers.get(day!(2), 1), None);
+            assert_eq!(answers.get(day!(2), 2), Some("8"));
+        }
+
+        #[test]
+        fn overwrites_existing_parts() {
+            let mut answers = get_mock_answers();
+            answers.set(day!(3), 2, "467835");
+            answers.set(day!(1), 1, "143");
+            assert_eq!(answers.data.len(), 2);
+            assert_eq!(answers.get(day!(3), 2), Some("467835"));
+            assert_eq!(answers.get(day!(1), 1), Some("143"));
+        }
+    }
+
+    mod verify {
+        use super::get_mock_answers;
+        use crate::{day, template::answers::Verdict};
+
+        #[test]
+        fn handles_matching_answers() {
+            let answers = get_mock_answers();
+            assert_eq!(answers.verify(day!(1), 1, Some("142")), Verdict::Pass);
+        }
+
+        #[test]
+        fn handles_mismatching_answers() {
+            let answers = get_mock_answers();
+            let expected = Verdict::Fail {
+                expected: "281".into(),
+            };
+            assert_eq!(answers.verify(day!(1), 2, Some("280")), expected);
+            assert_eq!(answers.verify(day!(1), 2, None), expected);
+        }
+
+        #[test]
+        fn handles_missing_answers() {
+            let answers = get_mock_answers();
+            assert_eq!(answers.verify(day!(3), 2, Some("1")), Verdict::Missing);
+            assert_eq!(answers.verify(day!(5), 1, None), Verdict::Missing);
+        }
+    }
+}
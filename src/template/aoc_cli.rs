@@ -4,7 +4,7 @@ use std::{
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{paths, Day, Year};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -33,8 +33,8 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(year: Option<Year>, day: Day) -> Result<Output, AocCommandError> {
+    let puzzle_path = get_puzzle_path(year, day);
 
     let args = build_args(
         "read",
@@ -43,15 +43,33 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
+        year,
         day,
     );
 
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+/// Downloads only the puzzle description, overwriting an outdated copy.
+pub fn download_puzzle(year: Option<Year>, day: Day) -> Result<Output, AocCommandError> {
+    let args = build_args(
+        "download",
+        &[
+            "--overwrite".into(),
+            "--puzzle-only".into(),
+            "--puzzle-file".into(),
+            get_puzzle_path(year, day),
+        ],
+        year,
+        day,
+    );
+
+    call_aoc_cli(&args)
+}
+
+pub fn download(year: Option<Year>, day: Day) -> Result<Output, AocCommandError> {
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_path(year, day);
 
     let args = build_args(
         "download",
@@ -62,6 +80,7 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
+        year,
         day,
     );
 
@@ -72,33 +91,45 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+pub fn submit(
+    year: Option<Year>,
+    day: Day,
+    part: u8,
+    result: &str,
+) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], year, day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+    // capture stdout, so the caller can evaluate the response.
+    let output = Command::new("aoc")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+fn get_input_path(year: Option<Year>, day: Day) -> String {
+    paths::input_path(year, day).to_string_lossy().into()
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+fn get_puzzle_path(year: Option<Year>, day: Day) -> String {
+    paths::puzzle_path(year, day).to_string_lossy().into()
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], year: Option<Year>, day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
+    // without an explicit year, fall back to the default year of the single-year layout.
+    if let Some(year) = year.or_else(Year::from_env) {
         cmd_args.push("--year".into());
         cmd_args.push(year.to_string());
     }
@@ -0,0 +1,425 @@
+/// Built-in client for the Advent of Code website.
+///
+/// Authenticates with the session cookie found in the `AOC_SESSION` env variable, or in the file at
+/// `AOC_SESSION_FILE` (defaults to `~/.adventofcode.session`, which is also used by aoc-cli).
+/// Requests go to `AOC_BASE_URL` if set, which allows pointing the client to a local stand-in server.
+/// Requests identify their operator with the user agent in `AOC_USER_AGENT`, see [`user_agent`].
+use std::{env, fmt::Display, fs, path::PathBuf, time::Duration};
+
+use crate::template::{Day, Year};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The backends that can be used to talk to the Advent of Code website.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// The built-in [`AocClient`].
+    Native,
+    /// The external `aoc` binary, see [`crate::template::aoc_cli`].
+    AocCli,
+}
+
+impl Backend {
+    /// Reads the backend from the `AOC_BACKEND` env variable. Defaults to the built-in client.
+    pub fn from_env() -> Self {
+        match env::var("AOC_BACKEND").as_deref() {
+            Ok("aoc-cli") => Backend::AocCli,
+            _ => Backend::Native,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    YearNotSet,
+    Request(String),
+    BadStatus(u16),
+    UnexpectedResponse,
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or create the file `~/.adventofcode.session`."
+            ),
+            AocClientError::YearNotSet => {
+                write!(f, "no year set. Pass `--year` or set `AOC_YEAR`.")
+            }
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus(400) => {
+                write!(f, "server responded with status 400, is the session cookie valid?")
+            }
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::UnexpectedResponse => {
+                write!(f, "server responded with an unexpected page.")
+            }
+        }
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+}
+
+impl AocClient {
+    /// Creates a client for `year`, falling back to `AOC_YEAR` if not set.
+    pub fn new(year: Option<Year>) -> Result<Self, AocClientError> {
+        let year = year
+            .or_else(Year::from_env)
+            .ok_or(AocClientError::YearNotSet)?;
+
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Ok(Self::with_base_url(year, read_session()?, &base_url))
+    }
+
+    /// Creates a client with explicit settings, e.g. for a local stand-in server.
+    pub fn with_base_url(year: Year, session: String, base_url: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&user_agent(env::var("AOC_USER_AGENT").ok()))
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').into(),
+            session,
+            year,
+        }
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Fetches the description of a day as markdown. Contains part two once part one is solved.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(day))?;
+
+        let description: Vec<String> = find_articles(&html)
+            .into_iter()
+            .map(html_to_markdown)
+            .collect();
+
+        if description.is_empty() {
+            return Err(AocClientError::UnexpectedResponse);
+        }
+
+        Ok(description.join("\n"))
+    }
+
+    /// Submits an answer and returns the message of the response page as markdown.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(to_client_error)?
+            .into_string()
+            .map_err(|e| AocClientError::Request(e.to_string()))?;
+
+        find_articles(&html)
+            .first()
+            .map(|article| html_to_markdown(article))
+            .ok_or(AocClientError::UnexpectedResponse)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        self.agent
+            .get(url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(to_client_error)?
+            .into_string()
+            .map_err(|e| AocClientError::Request(e.to_string()))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn to_client_error(e: ureq::Error) -> AocClientError {
+    match e {
+        ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+        ureq::Error::Transport(e) => AocClientError::Request(e.to_string()),
+    }
+}
+
+/// Advent of Code asks automated tools to identify who operates them, e.g. with a link to their repository
+/// and an email address. Falls back to the `repository` and the name of this package, if not configured.
+fn user_agent(configured: Option<String>) -> String {
+    if let Some(agent) = configured.filter(|agent| !agent.trim().is_empty()) {
+        return agent.trim().into();
+    }
+
+    let package = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+    match env!("CARGO_PKG_REPOSITORY") {
+        "" => package.into(),
+        repository => format!("{repository} via {package}"),
+    }
+}
+
+fn read_session() -> Result<String, AocClientError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session.trim().into());
+    }
+
+    let path = env::var("AOC_SESSION_FILE")
+        .map(PathBuf::from)
+        .or_else(|_| {
+            env::var("HOME")
+                .or_else(|_| env::var("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(".adventofcode.session"))
+        });
+
+    path.ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or(AocClientError::SessionNotFound)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the inner HTML of all `<article>` elements of a page.
+fn find_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(content_start) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..].find("</article>") else {
+            break;
+        };
+
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end..];
+    }
+
+    articles
+}
+
+/// Converts the subset of HTML used in puzzle descriptions to markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+
+    let mut in_pre = false;
+    let mut in_code = false;
+    // emphasis inside inline code is moved around it, e.g. `<code><em>x</em></code>` becomes *`x`*.
+    let mut em_in_code = false;
+    let mut link_targets: Vec<String> = vec![];
+
+    while !rest.is_empty() {
+        let tag_start = rest.find('<').unwrap_or(rest.len());
+        let text = &rest[..tag_start];
+
+        // line breaks between block elements are formatting of the page, except inside code blocks.
+        if in_pre || !text.trim().is_empty() || !text.contains('\n') {
+            out.push_str(&decode_entities(text));
+        }
+
+        if tag_start == rest.len() {
+            break;
+        }
+
+        let Some(tag_end) = rest[tag_start..].find('>').map(|i| tag_start + i) else {
+            break;
+        };
+
+        let tag = &rest[tag_start + 1..tag_end];
+        rest = &rest[tag_end + 1..];
+
+        let (is_closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+
+        let name = tag.split_whitespace().next().unwrap_or_default();
+
+        match (name, is_closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", false) if !in_pre => {
+                in_code = true;
+                out.push('`');
+            }
+            ("code", true) if !in_pre => {
+                in_code = false;
+                out.push('`');
+                if em_in_code {
+                    em_in_code = false;
+                    out.push('*');
+                }
+            }
+            ("em", false) if !in_pre => {
+                if in_code && out.ends_with('`') {
+                    em_in_code = true;
+                    out.insert(out.len() - 1, '*');
+                } else {
+                    out.push('*');
+                }
+            }
+            ("em", true) if !in_pre && !em_in_code => out.push('*'),
+            ("a", false) => {
+                link_targets.push(get_attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true) => {
+                let target = link_targets.pop().unwrap_or_default();
+                out.push_str(&format!("]({target})"));
+            }
+            ("li", false) => out.push_str("- "),
+            ("li", true) | ("br", _) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            _ => {}
+        }
+    }
+
+    format!("{}\n", out.trim())
+}
+
+fn get_attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')? + start;
+    Some(decode_entities(&tag[start..end]))
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find_articles, html_to_markdown, user_agent, AocClient};
+    use crate::{day, template::Year};
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Serves a single request with `body` and returns the request line and headers.
+    fn serve_once(body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let reader = BufReader::new(stream.try_clone().unwrap());
+
+            let request: Vec<String> = reader
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_inputs() {
+        let (base_url, server) = serve_once("1abc2\npqr3stu8vwx\n");
+        let client = AocClient::with_base_url(Year::new(2023).unwrap(), "secret".into(), &base_url);
+
+        assert_eq!(client.input(day!(1)).unwrap(), "1abc2\npqr3stu8vwx\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2023/day/1/input HTTP/1.1");
+        assert!(request.iter().any(|l| l == "Cookie: session=secret"));
+        assert!(request
+            .iter()
+            .any(|l| l.to_lowercase().starts_with("user-agent: ")));
+    }
+
+    #[test]
+    fn identifies_the_operator() {
+        assert_eq!(
+            user_agent(Some(" github.com/me/aoc by me@example.com ".into())),
+            "github.com/me/aoc by me@example.com"
+        );
+        assert_eq!(
+            user_agent(None),
+            concat!("advent_of_code/", env!("CARGO_PKG_VERSION"))
+        );
+        assert_eq!(user_agent(Some("".into())), user_agent(None));
+    }
+
+    #[test]
+    fn fetches_puzzles() {
+        let (base_url, server) = serve_once(
+            "<main><article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2><p>Hi</p></article></main>",
+        );
+        let client = AocClient::with_base_url(Year::new(2023).unwrap(), "secret".into(), &base_url);
+
+        assert_eq!(
+            client.puzzle(day!(1)).unwrap(),
+            "## --- Day 1: Trebuchet?! ---\n\nHi\n"
+        );
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn finds_articles() {
+        let html = "<main><article class=\"day-desc\"><p>a</p></article><p>x</p><article><p>b</p></article></main>";
+        assert_eq!(find_articles(html), vec!["<p>a</p>", "<p>b</p>"]);
+    }
+
+    #[test]
+    fn converts_html_to_markdown() {
+        let html = "<h2>--- Day 1 ---</h2>\n<p>The <em>calibration</em> for <a href=\"/2023/about\">this</a> is <code>1abc2</code>, totalling <code><em>142</em></code>.</p>\n<pre><code>1abc2\n&lt;pqr3&gt;\n</code></pre>\n<ul>\n<li>one</li>\n<li>two</li>\n</ul>";
+
+        assert_eq!(
+            html_to_markdown(html),
+            "## --- Day 1 ---\n\nThe *calibration* for [this](/2023/about) is `1abc2`, totalling *`142`*.\n\n```\n1abc2\n<pqr3>\n```\n\n- one\n- two\n"
+        );
+    }
+}
@@ -1,5 +1,107 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    all_days,
+    report::{Report, Status},
+    run_multi::{run_multi, MultiRun, MultiRunOptions},
+    ANSI_BOLD, ANSI_RESET,
+};
+
+/// Answers are cut off after this many characters to keep the summary aligned.
+const MAX_ANSWER_WIDTH: usize = 20;
+
+pub fn handle(options: MultiRunOptions) {
+    let run = run_multi(&all_days().collect(), options);
+
+    print_summary(&run);
+
+    // allows using `cargo all` as a gate, e.g. in CI.
+    if run.has_failures() {
+        process::exit(1);
+    }
+}
+
+fn print_summary(run: &MultiRun) {
+    println!("\n{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!(
+        "{:<6} {:<22} {:<22} {:>10}",
+        "Day", "Part 1", "Part 2", "Time"
+    );
+
+    for day in &run.days {
+        let reports: Vec<&Report> = run.reports.iter().filter(|r| r.day == *day).collect();
+
+        let (part_1, part_2) = if run.crashed.contains(day) {
+            ("✖ crashed".to_string(), "✖ crashed".to_string())
+        } else if run.missing.contains(day) {
+            ("✖ no report".to_string(), "✖ no report".to_string())
+        } else {
+            (
+                format_part(&reports, 1, reports.is_empty()),
+                format_part(&reports, 2, reports.is_empty()),
+            )
+        };
+
+        println!(
+            "{:<6} {:<22} {:<22} {:>10}",
+            day.to_string(),
+            part_1,
+            part_2,
+            format_time(&reports)
+        );
+    }
+
+    let failures = run.failures();
+
+    if failures > 0 {
+        println!("\n{ANSI_BOLD}{failures} failed{ANSI_RESET}");
+    }
+}
+
+/// Formats the outcome of a part, e.g. its answer or `✖ panicked`.
+fn format_part(reports: &[&Report], part: u8, day_is_skipped: bool) -> String {
+    if day_is_skipped {
+        return "-".into();
+    }
+
+    match reports.iter().find(|r| r.part == part) {
+        Some(report) => match &report.status {
+            Status::Solved => format_answer(report.answer.as_deref().unwrap_or_default()),
+            Status::Unsolved => "✖".into(),
+            Status::Timeout => "✖ timeout".into(),
+            Status::Panicked(_) => "✖ panicked".into(),
+            Status::Error(_) => "✖ error".into(),
+        },
+        None => "-".into(),
+    }
+}
+
+fn format_answer(answer: &str) -> String {
+    if answer.contains('\n') {
+        return "▼ (multi-line)".into();
+    }
+
+    if answer.chars().count() > MAX_ANSWER_WIDTH {
+        let truncated: String = answer.chars().take(MAX_ANSWER_WIDTH - 1).collect();
+        return format!("{truncated}…");
+    }
+
+    answer.into()
+}
+
+/// Sum of the mean execution times of the parse step and both parts.
+fn format_time(reports: &[&Report]) -> String {
+    let total: Duration = reports
+        .iter()
+        .filter(|r| r.status == Status::Solved)
+        .filter_map(|r| r.stats.as_ref())
+        .map(|stats| stats.mean)
+        .sum();
+
+    if total.is_zero() {
+        "-".into()
+    } else {
+        format!("{total:.1?}")
+    }
 }
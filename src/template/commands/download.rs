@@ -1,14 +1,63 @@
-use crate::template::{aoc_cli, Day};
-use std::process;
+use crate::template::aoc_client::{AocClient, Backend};
+use crate::template::{aoc_cli, paths, Day, Year};
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+pub fn handle(year: Option<Year>, day: Day) {
+    if let Err(e) = download(year, day) {
+        eprintln!("{e}");
         process::exit(1);
     }
+}
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+/// Downloads the input and the puzzle description of a day to the data directory.
+pub fn download(year: Option<Year>, day: Day) -> Result<(), String> {
+    // the data directories of a year do not exist until its first download.
+    for dir in [
+        paths::data_dir(year).join("inputs"),
+        paths::data_dir(year).join("puzzles"),
+    ] {
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("failed to create directory {}: {e}", dir.display()))?;
+    }
+
+    match Backend::from_env() {
+        Backend::AocCli => download_with_aoc_cli(year, day),
+        Backend::Native => download_with_client(year, day),
+    }
+}
+
+fn download_with_client(year: Option<Year>, day: Day) -> Result<(), String> {
+    let client = AocClient::new(year).map_err(|e| format!("failed to download: {e}"))?;
+
+    let input_path = paths::input_path(year, day);
+    let puzzle_path = paths::puzzle_path(year, day);
+
+    let (input, puzzle) = client
+        .input(day)
+        .and_then(|input| Ok((input, client.puzzle(day)?)))
+        .map_err(|e| format!("failed to download: {e}"))?;
+
+    fs::write(&input_path, input)
+        .and(fs::write(&puzzle_path, puzzle))
+        .map_err(|e| format!("failed to write files: {e}"))?;
+
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+
+    Ok(())
+}
+
+fn download_with_aoc_cli(year: Option<Year>, day: Day) -> Result<(), String> {
+    if aoc_cli::check().is_err() {
+        return Err("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.".into());
+    }
+
+    aoc_cli::download(year, day).map_err(|e| format!("failed to call aoc-cli: {e}"))?;
+    Ok(())
 }
@@ -0,0 +1,334 @@
+use std::path::Path;
+use std::{fs, process};
+
+use crate::template::{markdown, paths, Day, Year};
+
+/// Assertion of the scaffolded tests, replaced with the expected answer.
+const EMPTY_ASSERTION: &str = "assert_eq!(result, None);";
+
+/// How the scaffolded tests read the example, and how they read the example of part two instead.
+const EXAMPLE_READERS: [(&str, &str); 2] = [
+    (
+        "read_file(\"examples\", DAY)",
+        "read_file_part(\"examples\", DAY, 2)",
+    ),
+    (
+        "read_file_for_year(\"examples\", YEAR, DAY)",
+        "read_file_part_for_year(\"examples\", YEAR, DAY, 2)",
+    ),
+];
+
+pub fn handle(
+    year: Option<Year>,
+    day: Day,
+    block: Option<usize>,
+    part_two_block: Option<usize>,
+    force: bool,
+) {
+    let puzzle_path = paths::puzzle_path(year, day);
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Failed to read puzzle \"{}\". Download it with `cargo download {day}` first.",
+            puzzle_path.display()
+        );
+        process::exit(1);
+    };
+
+    let (example, part_two_example) = match find_examples(&puzzle, block, part_two_block) {
+        Ok(examples) => examples,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let mut examples = vec![(paths::example_path(year, day, None), example)];
+
+    if let Some(example) = part_two_example {
+        examples.push((paths::example_path(year, day, Some(2)), example));
+    }
+
+    if !force {
+        if let Some((path, _)) = examples.iter().find(|(path, _)| is_non_empty(path)) {
+            eprintln!(
+                "Example file \"{}\" is not empty. Use `--force` to overwrite it.",
+                path.display()
+            );
+            process::exit(1);
+        }
+    }
+
+    for (path, example) in &examples {
+        if let Err(e) = fs::create_dir_all(paths::data_dir(year).join("examples"))
+            .and_then(|()| fs::write(path, example))
+        {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+
+    println!(
+        "Wrote code block {} to example file \"{}\"",
+        block.unwrap_or(1),
+        examples[0].0.display()
+    );
+
+    if let (Some(index), Some((path, _))) = (part_two_block, examples.get(1)) {
+        println!(
+            "Wrote code block {index} of part two to example file \"{}\"",
+            path.display()
+        );
+    }
+
+    let module_path = paths::bin_path(year, day);
+
+    let Ok(mut module) = fs::read_to_string(&module_path) else {
+        eprintln!("Failed to read module file \"{module_path}\", skipped the tests.");
+        return;
+    };
+
+    for (part, test_name) in [(1, "test_part_one"), (2, "test_part_two")] {
+        let Some(answer) = markdown::example_answer(&puzzle, part) else {
+            println!("Part {part}: no example answer found");
+            continue;
+        };
+
+        let reads_part_two = part == 2 && part_two_block.is_some();
+
+        match fill_in_test(&module, test_name, &answer, reads_part_two) {
+            Some(filled) => {
+                module = filled;
+                println!("Part {part}: expecting {answer}");
+            }
+            None => {
+                println!("Part {part}: found {answer}, but `{test_name}` already expects a value")
+            }
+        }
+    }
+
+    if let Err(e) = fs::write(&module_path, module) {
+        eprintln!("Failed to write module file: {e}");
+        process::exit(1);
+    }
+}
+
+/// Returns code block `block` of part one, and code block `part_two_block` of part two if set.
+/// Both count from 1 and default to the first code block.
+fn find_examples(
+    puzzle: &str,
+    block: Option<usize>,
+    part_two_block: Option<usize>,
+) -> Result<(String, Option<String>), String> {
+    let example = find_code_block(puzzle, 1, block.unwrap_or(1))?;
+
+    // code blocks of part two are often intermediate states of the example of part one, e.g. a grid
+    // after some steps, so part two only gets its own example if asked for.
+    let part_two_example = part_two_block
+        .map(|index| find_code_block(puzzle, 2, index))
+        .transpose()?;
+
+    Ok((example, part_two_example))
+}
+
+fn find_code_block(puzzle: &str, part: u8, index: usize) -> Result<String, String> {
+    let blocks =
+        markdown::code_blocks(markdown::part_description(puzzle, part).unwrap_or_default());
+
+    index
+        .checked_sub(1)
+        .and_then(|i| blocks.get(i))
+        .cloned()
+        .ok_or_else(|| {
+            format!(
+                "Failed to find code block {index}, part {part} contains {} code blocks.",
+                blocks.len()
+            )
+        })
+}
+
+fn is_non_empty(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Replaces the empty assertion of a test with the expected answer. If `reads_part_two` is set,
+/// the test reads the example of part two instead, e.g. `01-2.txt`.
+/// Returns [`None`] if the test does not exist or was changed after scaffolding.
+fn fill_in_test(
+    module: &str,
+    test_name: &str,
+    answer: &str,
+    reads_part_two: bool,
+) -> Option<String> {
+    let test_start = module.find(&format!("fn {test_name}()"))?;
+    // the assertion must be part of this test, not of one further down.
+    let test_end = module[test_start + 1..]
+        .find("fn ")
+        .map_or(module.len(), |i| test_start + 1 + i);
+
+    let test = &module[test_start..test_end];
+
+    if !test.contains(EMPTY_ASSERTION) {
+        return None;
+    }
+
+    let expected = if answer.parse::<i128>().is_ok() {
+        answer.to_string()
+    } else {
+        format!("{answer:?}.into()")
+    };
+
+    let mut filled = test.replacen(
+        EMPTY_ASSERTION,
+        &format!("assert_eq!(result, Some({expected}));"),
+        1,
+    );
+
+    if reads_part_two {
+        for (reader, part_two_reader) in EXAMPLE_READERS {
+            filled = filled.replacen(reader, part_two_reader, 1);
+        }
+    }
+
+    Some(format!(
+        "{}{filled}{}",
+        &module[..test_start],
+        &module[test_end..]
+    ))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{fill_in_test, find_examples};
+
+    const MODULE: &str = r#"#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+    #[test]
+    fn fills_in_numeric_answers() {
+        let module = fill_in_test(MODULE, "test_part_one", "142", false).unwrap();
+        assert_eq!(module.matches("assert_eq!(result, Some(142));").count(), 1);
+        assert_eq!(module.matches("assert_eq!(result, None);").count(), 1);
+        assert!(module.contains("fn test_part_two() {\n        let result = part_two(&advent_of_code::template::read_file(\"examples\", DAY));\n        assert_eq!(result, None);"));
+    }
+
+    #[test]
+    fn fills_in_string_answers() {
+        let module = fill_in_test(MODULE, "test_part_one", "a,b", false).unwrap();
+        assert!(module.contains(r#"assert_eq!(result, Some("a,b".into()));"#));
+    }
+
+    #[test]
+    fn skips_filled_in_tests() {
+        let module = fill_in_test(MODULE, "test_part_one", "142", false).unwrap();
+        assert_eq!(fill_in_test(&module, "test_part_one", "142", false), None);
+        assert_eq!(fill_in_test(MODULE, "test_part_three", "142", false), None);
+    }
+
+    #[test]
+    fn only_fills_in_the_named_test() {
+        // the empty assertion of `test_part_two` must not be filled in for `test_part_one`.
+        let module = MODULE.replacen(
+            "assert_eq!(result, None);",
+            "assert_eq!(result, Some(1));",
+            1,
+        );
+        assert_eq!(fill_in_test(&module, "test_part_one", "142", false), None);
+
+        let module = fill_in_test(&module, "test_part_two", "281", false).unwrap();
+        assert!(module.contains("assert_eq!(result, Some(1));"));
+        assert!(module.contains("assert_eq!(result, Some(281));"));
+    }
+
+    #[test]
+    fn reads_the_example_of_part_two() {
+        let module = fill_in_test(MODULE, "test_part_two", "281", true).unwrap();
+        assert!(
+            module.contains("part_one(&advent_of_code::template::read_file(\"examples\", DAY));")
+        );
+        assert!(module.contains(
+            "part_two(&advent_of_code::template::read_file_part(\"examples\", DAY, 2));"
+        ));
+
+        let module = MODULE.replace(
+            "read_file(\"examples\", DAY)",
+            "read_file_for_year(\"examples\", YEAR, DAY)",
+        );
+        let module = fill_in_test(&module, "test_part_two", "281", true).unwrap();
+        assert!(module.contains("read_file_part_for_year(\"examples\", YEAR, DAY, 2)"));
+    }
+
+    const PUZZLE: &str = "## --- Day 14: Parabolic Reflector Dish ---
+
+```
+O....#....
+.#..O.....
+```
+
+Start by tilting the lever so all of the rocks will slide north:
+
+```
+O...O#....
+.#........
+```
+
+The total load is *`136`*.
+
+## --- Part Two ---
+
+After 1 cycle:
+
+```
+.....#....
+....#...O#
+```
+
+Run the spin cycle for 1000000000 cycles. Afterward, the total load is *`64`*.
+";
+
+    #[test]
+    fn uses_the_example_of_part_one_for_both_parts() {
+        let (example, part_two_example) = find_examples(PUZZLE, None, None).unwrap();
+        assert_eq!(example, "O....#....\n.#..O.....\n");
+        assert_eq!(part_two_example, None);
+
+        let (example, _) = find_examples(PUZZLE, Some(2), None).unwrap();
+        assert_eq!(example, "O...O#....\n.#........\n");
+    }
+
+    #[test]
+    fn finds_the_example_of_part_two_if_asked_for() {
+        let (example, part_two_example) = find_examples(PUZZLE, None, Some(1)).unwrap();
+        assert_eq!(example, "O....#....\n.#..O.....\n");
+        assert_eq!(
+            part_two_example,
+            Some(".....#....\n....#...O#\n".to_string())
+        );
+
+        assert!(find_examples(PUZZLE, None, Some(2)).is_err());
+        assert!(find_examples(PUZZLE, Some(0), None).is_err());
+        assert!(find_examples(
+            PUZZLE.split("## --- Part Two").next().unwrap(),
+            None,
+            Some(1)
+        )
+        .is_err());
+    }
+}
@@ -1,15 +1,74 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::{aoc_cli, Day};
+use crate::template::aoc_client::{AocClient, Backend};
+use crate::template::{aoc_cli, markdown, paths, Day, Year};
 
-pub fn handle(day: Day) {
+pub fn handle(year: Option<Year>, day: Day, refresh: bool) {
+    let cached = fs::read_to_string(paths::puzzle_path(year, day)).ok();
+
+    let puzzle = match cached {
+        Some(puzzle) if !refresh => puzzle,
+        cached => match fetch(year, day) {
+            Ok(puzzle) => puzzle,
+            // e.g. when offline, the cached copy is better than nothing.
+            Err(e) => {
+                eprintln!("failed to read puzzle: {e}");
+
+                let Some(puzzle) = cached else {
+                    process::exit(1);
+                };
+
+                eprintln!("showing the cached copy instead.");
+                puzzle
+            }
+        },
+    };
+
+    print!("{}", markdown::render(&puzzle));
+
+    // the description only contains part two once part one is solved.
+    if !markdown::has_part_two(&puzzle) {
+        let year_arg = year
+            .map(|year| format!(" --year {year}"))
+            .unwrap_or_default();
+        println!("\nOnce part one is solved, run `cargo read {day}{year_arg} --refresh` to show part two.");
+    }
+}
+
+/// Downloads the puzzle description to the cache and returns it.
+fn fetch(year: Option<Year>, day: Day) -> Result<String, String> {
+    let puzzle_path = paths::puzzle_path(year, day);
+
+    if let Some(dir) = puzzle_path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("failed to create directory {}: {e}", dir.display()))?;
+    }
+
+    match Backend::from_env() {
+        Backend::AocCli => fetch_with_aoc_cli(year, day)?,
+        Backend::Native => fetch_with_client(year, day)?,
+    }
+
+    fs::read_to_string(&puzzle_path)
+        .map_err(|e| format!("failed to read \"{}\": {e}", puzzle_path.display()))
+}
+
+fn fetch_with_client(year: Option<Year>, day: Day) -> Result<(), String> {
+    let puzzle = AocClient::new(year)
+        .and_then(|client| client.puzzle(day))
+        .map_err(|e| e.to_string())?;
+
+    let puzzle_path = paths::puzzle_path(year, day);
+
+    fs::write(&puzzle_path, puzzle)
+        .map_err(|e| format!("failed to write \"{}\": {e}", puzzle_path.display()))
+}
+
+fn fetch_with_aoc_cli(year: Option<Year>, day: Day) -> Result<(), String> {
     if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+        return Err("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.".into());
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    aoc_cli::download_puzzle(year, day).map_err(|e| format!("failed to call aoc-cli: {e}"))?;
+    Ok(())
 }
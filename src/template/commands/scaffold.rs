@@ -1,26 +1,95 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{markdown, paths, Day, Year};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+pub const DEFAULT_TEMPLATE: &str = "default";
+pub const DEFAULT_ANSWER_TYPE: &str = "u32";
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Names of the templates in `./src/templates`.
+fn available_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(paths::templates_dir())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+
+            if path.extension()? != "txt" {
+                return None;
+            }
+
+            Some(path.file_stem()?.to_string_lossy().into_owned())
+        })
+        .collect();
+
+    names.sort();
+    names
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Fills in the placeholders of a template.
+fn render_template(template: &str, year: Option<Year>, day: Day, answer_type: &str) -> String {
+    // the title is known if the puzzle was downloaded before scaffolding.
+    let title = fs::read_to_string(paths::puzzle_path(year, day))
+        .ok()
+        .and_then(|puzzle| markdown::title(&puzzle))
+        .unwrap_or_else(|| format!("Day {}", day.into_inner()));
+
+    // solutions of the year layout read their examples from the directory of their year.
+    let read_example = if year.is_some() {
+        "read_file_for_year(\"examples\", YEAR, DAY)"
+    } else {
+        "read_file(\"examples\", DAY)"
+    };
+
+    let year = year
+        .or_else(Year::from_env)
+        .map(|year| year.to_string())
+        .unwrap_or_default();
+
+    template
+        .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+        .replace("%YEAR%", &year)
+        .replace("%TITLE%", &title)
+        .replace("%ANSWER_TYPE%", answer_type)
+        .replace("%READ_EXAMPLE%", read_example)
+}
+
+pub fn handle(year: Option<Year>, day: Day, template: &str, answer_type: &str) {
+    let input_path = paths::input_path(year, day).to_string_lossy().to_string();
+    let example_path = paths::example_path(year, day, None)
+        .to_string_lossy()
+        .to_string();
+    let module_path = paths::bin_path(year, day);
+
+    let Ok(module_template) = fs::read_to_string(paths::template_path(template)) else {
+        eprintln!(
+            "Failed to read template \"{template}\". Available templates: {}",
+            available_templates().join(", ")
+        );
+        process::exit(1);
+    };
+
+    let module = render_template(&module_template, year, day, answer_type);
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -30,11 +99,7 @@ pub fn handle(day: Day) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(module.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -44,26 +109,34 @@ pub fn handle(day: Day) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    // keep the input if it was downloaded before scaffolding.
+    if !Path::new(&input_path).exists() {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", &input_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    if !Path::new(&example_path).exists() {
+        match create_file(&example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", &example_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    match year {
+        Some(year) => println!("🎄 Type `cargo solve {day} --year {year}` to run your solution."),
+        None => println!("🎄 Type `cargo solve {day}` to run your solution."),
+    }
 }
@@ -1,30 +1,111 @@
-use std::process::{Command, Stdio};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
 
-use crate::template::Day;
+use crate::template::{paths, runner::InputSource, Day, Year, ANSI_ITALIC, ANSI_RESET};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Interval between two checks for changed files in watch mode.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
 
-    if dhat {
+const ANSI_CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Options that control how a day is run.
+#[derive(Clone, Debug, Default)]
+pub struct SolveOptions {
+    pub release: bool,
+    pub dhat: bool,
+    /// Submit the result of this part.
+    pub submit_part: Option<u8>,
+    /// Give up on a part if its first execution takes longer than this.
+    pub timeout: Option<Duration>,
+    /// Where to read the input from.
+    pub input: InputSource,
+    /// Re-run the day whenever its files change.
+    pub watch: bool,
+    /// Run the tests of the day before every run in watch mode.
+    pub test: bool,
+}
+
+pub fn handle(year: Option<Year>, day: Day, options: &SolveOptions) {
+    if options.watch && (options.submit_part.is_some() || options.input == InputSource::Stdin) {
+        eprintln!("`--watch` can not be combined with `--submit` or `--stdin`.");
+        process::exit(1);
+    }
+
+    let cmd_args = build_args(year, day, options);
+
+    if !options.watch {
+        run_cargo(&cmd_args);
+        return;
+    }
+
+    let mut last_modified = modification_times(&watched_files(year, day, &options.input));
+
+    loop {
+        print!("{ANSI_CLEAR_SCREEN}");
+
+        if options.test {
+            run_cargo(&["test".into(), "--bin".into(), paths::bin_name(year, day)]);
+        }
+
+        run_cargo(&cmd_args);
+
+        println!("\n{ANSI_ITALIC}Watching for changes, press Ctrl-C to stop.{ANSI_RESET}");
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+
+            // files are listed again, so new library modules are watched too.
+            let modified = modification_times(&watched_files(year, day, &options.input));
+
+            if modified != last_modified {
+                last_modified = modified;
+                break;
+            }
+        }
+    }
+}
+
+fn build_args(year: Option<Year>, day: Day, options: &SolveOptions) -> Vec<String> {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        paths::bin_name(year, day),
+    ];
+
+    if options.dhat {
         cmd_args.extend([
             "--profile".to_string(),
             "dhat".to_string(),
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
+    } else if options.release {
         cmd_args.push("--release".to_string());
     }
 
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if let Some(submit_part) = options.submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(timeout) = options.timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.as_secs_f64().to_string());
+    }
+
+    cmd_args.extend(options.input.to_args());
+
+    cmd_args
+}
+
+fn run_cargo(args: &[String]) {
     let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+        .args(args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
@@ -32,3 +113,54 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Files that trigger a new run when changed: the solution, the library and the data files of the day.
+fn watched_files(year: Option<Year>, day: Day, input: &InputSource) -> Vec<PathBuf> {
+    let mut files = vec![
+        PathBuf::from(paths::bin_path(year, day)),
+        paths::input_path(year, day),
+        paths::example_path(year, day, None),
+        paths::example_path(year, day, Some(1)),
+        paths::example_path(year, day, Some(2)),
+    ];
+
+    if let InputSource::File(path) = input {
+        files.push(path.clone());
+    }
+
+    library_files(Path::new("./src"), &mut files);
+
+    files
+}
+
+/// Collects the source files of the library, i.e. all files in `./src` except for the solutions.
+fn library_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    let mut paths: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+    // keeps the order stable between two checks.
+    paths.sort();
+
+    for path in paths {
+        if path.is_dir() {
+            if !path.ends_with("bin") || dir != Path::new("./src") {
+                library_files(&path, files);
+            }
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// Paths of `files` with their modification times, `None` for files that do not exist (yet).
+fn modification_times(files: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    files
+        .iter()
+        .map(|file| {
+            let modified = fs::metadata(file).and_then(|m| m.modified()).ok();
+            (file.clone(), modified)
+        })
+        .collect()
+}
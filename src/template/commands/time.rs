@@ -1,40 +1,180 @@
 use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
-
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
-
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
-            }
+use crate::template::run_multi::{run_multi, MultiRunOptions};
+use crate::template::timings::{TimingDelta, Timings};
+use crate::template::{all_days, readme_benchmarks, Day, ANSI_BOLD, ANSI_RESET};
+
+/// Default percentage a part may get slower before `--compare` flags it as a regression.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    compare: Option<f64>,
+    options: MultiRunOptions,
+) {
+    let stored_timings = Timings::read_from_file(options.year);
+
+    let days_to_run = select_days(day, run_all, compare.is_some(), &stored_timings);
+
+    if options.jobs > 1 {
+        println!("Note: benching days concurrently, timings may interfere with each other.");
+    }
+
+    let timings = run_multi(
+        &days_to_run,
+        MultiRunOptions {
+            is_release: true,
+            is_timed: true,
+            ..options
         },
-        |day| HashSet::from([day]),
-    );
+    )
+    .timings
+    .unwrap();
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let has_regressions = compare
+        .is_some_and(|threshold| print_comparison(&stored_timings.compare(&timings), threshold));
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(options.year).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
-            }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+        if options.year.is_some() {
+            // the readme only holds the benchmarks of the single-year layout.
+            println!("Stored updated benchmarks.");
+        } else {
+            match readme_benchmarks::update(merged_timings) {
+                Ok(()) => {
+                    println!("Stored updated benchmarks.");
+                }
+                Err(_) => {
+                    eprintln!("Failed to store updated benchmarks.");
+                }
             }
         }
     }
+
+    if has_regressions {
+        process::exit(1);
+    }
+}
+
+/// Returns the days to bench: `day` if set, every day with `--all`, or every day with stored timings
+/// with `--compare`. Otherwise, days that are not fully benched yet.
+fn select_days(
+    day: Option<Day>,
+    run_all: bool,
+    compare: bool,
+    stored_timings: &Timings,
+) -> HashSet<Day> {
+    match day {
+        Some(day) => HashSet::from([day]),
+        None if run_all => all_days().collect(),
+        None if compare => all_days()
+            .filter(|day| stored_timings.has_day(*day))
+            .collect(),
+        None => all_days()
+            .filter(|day| !stored_timings.is_day_complete(*day))
+            .collect(),
+    }
+}
+
+/// Prints a table of timing deltas. Returns `true` if any part regressed beyond the threshold.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn print_comparison(deltas: &[TimingDelta], threshold: f64) -> bool {
+    let format_nanos = |nanos: f64| format!("{:.1?}", Duration::from_nanos(nanos.abs() as u64));
+
+    println!();
+    println!("{ANSI_BOLD}Comparison{ANSI_RESET} (threshold: +{threshold}%)");
+    println!("----------");
+
+    if deltas.is_empty() {
+        println!("No stored timings to compare against.");
+        return false;
+    }
+
+    println!(
+        "{:<6} {:<6} {:>10} {:>10} {:>11} {:>9}",
+        "Day", "Part", "Stored", "Current", "Δ", "Δ %"
+    );
+
+    let mut regressions = 0;
+
+    for delta in deltas {
+        let sign = if delta.delta_nanos() < 0.0 { "-" } else { "+" };
+
+        let line = format!(
+            "{:<6} {:<6} {:>10} {:>10} {:>11} {:>+8.1}%",
+            delta.day.to_string(),
+            delta.part,
+            format_nanos(delta.before_nanos),
+            format_nanos(delta.after_nanos),
+            format!("{sign}{}", format_nanos(delta.delta_nanos())),
+            delta.delta_percent()
+        );
+
+        if delta.is_regression(threshold) {
+            regressions += 1;
+            println!("{ANSI_BOLD}{line}  ▲ regression{ANSI_RESET}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    println!();
+    if regressions > 0 {
+        println!("{regressions} part(s) regressed by more than {threshold}%.");
+    } else {
+        println!("No regressions beyond {threshold}%.");
+    }
+
+    regressions > 0
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::select_days;
+    use crate::{day, template::timings::Timings};
+
+    fn get_mock_timings() -> Timings {
+        // day 1 is fully benched, only part 1 of day 3 is.
+        Timings::try_from(
+            r#"{ "data": [
+                { "day": "01", "part_1": "1ms", "part_2": "2ms", "total_nanos": 3000000 },
+                { "day": "03", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }
+            ] }"#
+                .to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn selects_days_with_stored_timings_to_compare() {
+        let timings = get_mock_timings();
+        assert_eq!(
+            select_days(None, false, true, &timings),
+            HashSet::from([day!(1), day!(3)])
+        );
+        assert_eq!(
+            select_days(Some(day!(2)), false, true, &timings),
+            HashSet::from([day!(2)])
+        );
+        assert_eq!(select_days(None, true, true, &timings).len(), 25);
+    }
+
+    #[test]
+    fn selects_days_that_are_not_fully_benched() {
+        let days = select_days(None, false, false, &get_mock_timings());
+        assert_eq!(days.len(), 24);
+        assert!(!days.contains(&day!(1)));
+        assert!(days.contains(&day!(3)));
+    }
 }
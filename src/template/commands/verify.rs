@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+use std::process;
+
+use crate::template::answers::{Answers, Verdict};
+use crate::template::run_multi::{run_multi, MultiRunOptions};
+use crate::template::{all_days, report::Status, Day, ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(day: Option<Day>, accept: bool, options: MultiRunOptions) {
+    let mut answers = Answers::read_from_file(options.year);
+
+    let days_to_run = day.map_or_else(|| all_days().collect(), |day| HashSet::from([day]));
+    let run = run_multi(
+        &days_to_run,
+        MultiRunOptions {
+            is_timed: false,
+            ..options
+        },
+    );
+
+    if accept {
+        let mut count = 0;
+
+        for report in run.reports.iter().filter(|r| r.status == Status::Solved) {
+            if let Some(answer) = &report.answer {
+                answers.set(report.day, report.part, answer);
+                count += 1;
+            }
+        }
+
+        if let Err(e) = answers.store_file(options.year) {
+            eprintln!("Failed to store accepted answers: {e}");
+            process::exit(1);
+        }
+
+        println!();
+        println!("Stored {count} accepted answer(s).");
+        return;
+    }
+
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut missing = 0;
+
+    println!();
+    println!("{ANSI_BOLD}Verification{ANSI_RESET}");
+    println!("------------");
+
+    for day in all_days().filter(|day| days_to_run.contains(day)) {
+        for part in 1..=2 {
+            let report = run.reports.iter().find(|r| r.day == day && r.part == part);
+            let answer = report.and_then(|r| r.answer.as_deref());
+
+            let status = match answers.verify(day, part, answer) {
+                // nothing to verify for parts that were neither run nor accepted before.
+                Verdict::Missing if report.is_none() => continue,
+                Verdict::Missing => {
+                    missing += 1;
+                    format!("? missing (got {})", answer.unwrap_or("✖"))
+                }
+                Verdict::Pass => {
+                    passed += 1;
+                    "✔ pass".into()
+                }
+                Verdict::Fail { expected } => {
+                    failed += 1;
+                    format!(
+                        "✖ fail (expected {expected}, got {})",
+                        answer.unwrap_or("✖")
+                    )
+                }
+            };
+
+            println!("Day {day} Part {part}: {status}");
+        }
+    }
+
+    println!();
+    println!("{passed} passed, {failed} failed, {missing} missing.");
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
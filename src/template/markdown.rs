@@ -0,0 +1,305 @@
+/// Renders the markdown of puzzle descriptions in the terminal.
+///
+/// Supports the subset written by `download`: headings, code blocks, lists, inline code, emphasis and links.
+/// Puzzle files written by aoc-cli escape some characters (e.g. `\---`), these escapes are removed.
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Heading of the second part of a puzzle description.
+pub const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// Whether a puzzle description contains the second part, i.e. part one was solved when it was downloaded.
+#[must_use]
+pub fn has_part_two(markdown: &str) -> bool {
+    markdown.contains(PART_TWO_HEADING)
+}
+
+/// Returns the title of a puzzle, e.g. `Day 1: Trebuchet?!`.
+#[must_use]
+pub fn title(markdown: &str) -> Option<String> {
+    markdown
+        .lines()
+        .map(|line| line.trim_start_matches(['#', ' ', '\\']))
+        .find(|line| line.starts_with("--- Day "))
+        .map(|line| line.trim_matches(['-', ' ']).to_string())
+}
+
+/// Returns the contents of all code blocks, in order.
+#[must_use]
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            match block.take() {
+                Some(content) => blocks.push(content),
+                None => block = Some(String::new()),
+            }
+        } else if let Some(content) = block.as_mut() {
+            content.push_str(line);
+            content.push('\n');
+        }
+    }
+
+    blocks
+}
+
+/// Returns the description of a part, or [`None`] for part two if it's not part of the puzzle yet.
+#[must_use]
+pub fn part_description(markdown: &str, part: u8) -> Option<&str> {
+    match (part, markdown.split_once(PART_TWO_HEADING)) {
+        (1, Some((part_one, _))) => Some(part_one),
+        (1, None) => Some(markdown),
+        (2, Some((_, part_two))) => Some(part_two),
+        _ => None,
+    }
+}
+
+/// Returns the answer to the example of a part, i.e. the last emphasized inline code of its description.
+#[must_use]
+pub fn example_answer(markdown: &str, part: u8) -> Option<String> {
+    part_description(markdown, part)?
+        .rsplit_once("*`")
+        .and_then(|(_, rest)| rest.split_once("`*"))
+        .map(|(answer, _)| answer.to_string())
+}
+
+/// Renders markdown as text with ANSI escape codes.
+#[must_use]
+pub fn render(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            out.push_str("    ");
+            out.push_str(line);
+        } else if let Some(heading) = line.strip_prefix('#') {
+            let heading = heading.trim_start_matches('#').trim();
+            out.push_str(&format!(
+                "{ANSI_BOLD}{}{ANSI_RESET}",
+                render_inline(heading)
+            ));
+        } else if line.starts_with("\\---") && line.ends_with("---") {
+            // headings in puzzle files written by aoc-cli are plain, escaped text.
+            out.push_str(&format!("{ANSI_BOLD}{}{ANSI_RESET}", render_inline(line)));
+        } else if let Some(item) = line.strip_prefix("- ").or(line.strip_prefix("* ")) {
+            out.push_str(&format!("  • {}", render_inline(item)));
+        } else {
+            out.push_str(&render_inline(line));
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Renders emphasis in bold, inline code in italics, and links as their text.
+fn render_inline(line: &str) -> String {
+    let mut out = String::new();
+    let line = strip_links(line);
+    let mut chars = line.chars().peekable();
+
+    let mut is_bold = false;
+    let mut is_code = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if !is_code => {
+                if let Some(escaped) = chars.next() {
+                    out.push(escaped);
+                }
+            }
+            '`' => {
+                is_code = !is_code;
+                out.push_str(if is_code { ANSI_ITALIC } else { ANSI_RESET });
+                if !is_code && is_bold {
+                    out.push_str(ANSI_BOLD);
+                }
+            }
+            '*' if !is_code => {
+                // `**` is emphasis too, e.g. in puzzle files written by aoc-cli.
+                if chars.peek() == Some(&'*') {
+                    chars.next();
+                }
+                is_bold = !is_bold;
+                out.push_str(if is_bold { ANSI_BOLD } else { ANSI_RESET });
+            }
+            c => out.push(c),
+        }
+    }
+
+    if is_bold || is_code {
+        out.push_str(ANSI_RESET);
+    }
+
+    out
+}
+
+/// Replaces links like `[text](url)` with their text. Other brackets, e.g. in `[1,[2,3]]`, are kept.
+fn strip_links(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut out = String::new();
+    let mut is_code = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if !is_code => {
+                // escapes are removed when rendering, an escaped `[` does not start a link.
+                out.extend(&chars[i..(i + 2).min(chars.len())]);
+                i += 2;
+                continue;
+            }
+            '`' => is_code = !is_code,
+            '[' if !is_code => {
+                if let Some((text, end)) = find_link(&chars, i) {
+                    out.extend(text);
+                    i = end;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+
+        out.push(chars[i]);
+        i += 1;
+    }
+
+    out
+}
+
+/// Returns the text of the link that starts at `start`, and the index after the link.
+fn find_link(chars: &[char], start: usize) -> Option<(&[char], usize)> {
+    let text_end = start + 1 + chars[start + 1..].iter().position(|&c| c == ']')?;
+
+    if chars.get(text_end + 1) != Some(&'(') {
+        return None;
+    }
+
+    let url_end = text_end + 2 + chars[text_end + 2..].iter().position(|&c| c == ')')?;
+
+    Some((&chars[start + 1..text_end], url_end + 1))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{code_blocks, example_answer, has_part_two, part_description, render, title};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    #[test]
+    fn renders_headings() {
+        assert_eq!(
+            render("## --- Day 1: Trebuchet?! ---\n\\--- Part Two ---"),
+            format!(
+                "{ANSI_BOLD}--- Day 1: Trebuchet?! ---{ANSI_RESET}\n{ANSI_BOLD}--- Part Two ---{ANSI_RESET}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn keeps_brackets_that_are_not_links() {
+        assert_eq!(
+            render("Packet [1,[2,3]] (left)\n"),
+            "Packet [1,[2,3]] (left)\n"
+        );
+        assert_eq!(render("a [b] c [d\n"), "a [b] c [d\n");
+        assert_eq!(
+            render("see [this](https://x.y/(z) and [that]\n"),
+            "see this and [that]\n"
+        );
+        assert_eq!(
+            render("`[1](2)` \\[x](y)\n"),
+            format!("{ANSI_ITALIC}[1](2){ANSI_RESET} [x](y)\n")
+        );
+    }
+
+    #[test]
+    fn renders_code_blocks() {
+        assert_eq!(
+            render("Example:\n\n```\n1abc2\n*x*\n```\n"),
+            "Example:\n\n    1abc2\n    *x*\n"
+        );
+    }
+
+    #[test]
+    fn renders_inline_styles() {
+        assert_eq!(
+            render("Sum is *`142`*, see [the list](/2023/day/1)."),
+            format!("Sum is {ANSI_BOLD}{ANSI_ITALIC}142{ANSI_RESET}{ANSI_BOLD}{ANSI_RESET}, see the list.\n")
+        );
+        assert_eq!(
+            render("- a **b**"),
+            format!("  • a {ANSI_BOLD}b{ANSI_RESET}\n")
+        );
+    }
+
+    const PUZZLE: &str = "## --- Day 1: Trebuchet?! ---
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+Adding these together produces *`142`*.
+
+## --- Part Two ---
+
+```
+two1nine
+```
+
+Adding these together produces *`281`*.
+
+What is the sum of all of the calibration values?
+";
+
+    #[test]
+    fn finds_titles() {
+        assert_eq!(title(PUZZLE), Some("Day 1: Trebuchet?!".into()));
+        assert_eq!(
+            title("\\--- Day 2: Cube Conundrum ---"),
+            Some("Day 2: Cube Conundrum".into())
+        );
+        assert_eq!(title("no title"), None);
+    }
+
+    #[test]
+    fn finds_code_blocks() {
+        assert_eq!(
+            code_blocks(PUZZLE),
+            vec!["1abc2\npqr3stu8vwx\n".to_string(), "two1nine\n".to_string()]
+        );
+    }
+
+    #[test]
+    fn finds_example_answers() {
+        assert_eq!(example_answer(PUZZLE, 1), Some("142".into()));
+        assert_eq!(example_answer(PUZZLE, 2), Some("281".into()));
+        assert_eq!(example_answer("no *answer*", 1), None);
+        assert_eq!(example_answer("*`142`*", 2), None);
+    }
+
+    #[test]
+    fn splits_parts() {
+        assert!(part_description(PUZZLE, 1).unwrap().contains("1abc2"));
+        assert!(!part_description(PUZZLE, 1).unwrap().contains("two1nine"));
+        assert!(part_description(PUZZLE, 2).unwrap().contains("two1nine"));
+        assert_eq!(part_description("no part two", 2), None);
+    }
+
+    #[test]
+    fn detects_part_two() {
+        assert!(!has_part_two("## --- Day 1: Trebuchet?! ---\n"));
+        assert!(has_part_two("## --- Part Two ---\n"));
+        assert!(has_part_two("\\--- Part Two ---\n"));
+    }
+}
@@ -1,15 +1,24 @@
 use std::{env, fs};
 
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod paths;
+pub mod report;
+pub mod run_multi;
 pub mod runner;
+pub mod statistics;
+pub mod submission;
 
 pub use day::*;
+pub use year::*;
 
+mod answers;
 mod day;
+mod markdown;
 mod readme_benchmarks;
-mod run_multi;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -18,51 +27,124 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 /// Helper function that reads a text file to a string.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
+    read_file_for_year(folder, None, day)
+}
+
+/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+#[must_use]
+pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+    read_file_part_for_year(folder, None, day, part)
+}
+
+/// Like [`read_file`], but reads from the data directory of `year` if set. E.g. like `data/2022/examples/01.txt`.
+#[must_use]
+pub fn read_file_for_year(folder: &str, year: Option<Year>, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(paths::data_dir(year))
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Like [`read_file_part`], but reads from the data directory of `year` if set.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part_for_year(folder: &str, year: Option<Year>, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(paths::data_dir(year))
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `DAY` and `YEAR` and sets up the input and runner for each part.
+///
+/// `YEAR` is derived from the name of the binary, it is only set for solutions of the year layout (e.g. `2022-01`).
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// A parse function can be passed before the part, e.g. `solution!(17, parse)`. It runs once, its output is
+/// passed to both parts by reference and its execution time is reported separately.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, [], [part_one, 1] [part_two, 2]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, [], [part_one, 1]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, [], [part_two, 2]);
+    };
+    ($day:expr, $parse:ident) => {
+        $crate::solution!(@impl $day, [$parse], [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, $parse:ident, 1) => {
+        $crate::solution!(@impl $day, [$parse], [part_one, 1]);
+    };
+    ($day:expr, $parse:ident, 2) => {
+        $crate::solution!(@impl $day, [$parse], [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, [$($parse:ident)?], $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The year of the current day, `None` for the single-year layout.
+        #[allow(dead_code)]
+        const YEAR: Option<$crate::template::Year> =
+            $crate::template::Year::from_bin_name(env!("CARGO_BIN_NAME"));
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
-        fn main() {
+        /// Runs the solution against the input selected in `options`.
+        /// Returns an error if the input could not be read.
+        #[allow(dead_code)]
+        pub fn run_parts(
+            options: &$crate::template::runner::RunOptions,
+        ) -> Result<Vec<$crate::template::report::Report>, String> {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let options = &RunOptions {
+                year: options.year.or(YEAR),
+                ..options.clone()
+            };
+            // parts run on their own threads, which requires the input to live for the rest of the program.
+            let input: &'static str = options.input.read(options.year, DAY)?.leak();
+            #[allow(unused_variables)]
+            let parts = [$($part),*];
+            $(
+                let input = match run_parse($parse, input, DAY, &parts, options) {
+                    Ok(parsed) => parsed,
+                    Err(reports) => return Ok(reports),
+                };
+            )?
+            let mut reports = vec![];
+            $(
+                if options.input.includes_part($part) {
+                    reports.push(run_part($func, input, DAY, $part, options));
+                }
+            )*
+            Ok(reports)
+        }
+
+        /// Registry entry for running the solution in-process.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::runner::Solution = $crate::template::runner::Solution {
+            year: YEAR,
+            day: DAY,
+            run: run_parts,
+        };
+
+        fn main() {
+            if let Err(e) = run_parts(&$crate::template::runner::RunOptions::from_args()) {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
         }
     };
 }
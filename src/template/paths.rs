@@ -0,0 +1,112 @@
+/// Locations of solution and data files.
+///
+/// Without a year, the single-year layout is used: `src/bin/NN.rs` and `data/inputs/NN.txt`.
+/// Years passed via `--year` get their own files: `src/bin/YYYY-NN.rs` and `data/YYYY/inputs/NN.txt`.
+use std::path::PathBuf;
+
+use crate::template::{Day, Year};
+
+/// Name of the solution binary of a day, e.g. `01` or `2022-01`.
+#[must_use]
+pub fn bin_name(year: Option<Year>, day: Day) -> String {
+    match year {
+        Some(year) => format!("{year}-{day}"),
+        None => day.to_string(),
+    }
+}
+
+/// Path of the solution module of a day.
+#[must_use]
+pub fn bin_path(year: Option<Year>, day: Day) -> String {
+    format!("./src/bin/{}.rs", bin_name(year, day))
+}
+
+/// Directory containing the scaffold templates.
+#[must_use]
+pub fn templates_dir() -> PathBuf {
+    PathBuf::from("./src/templates")
+}
+
+/// Path of a scaffold template, e.g. `./src/templates/grid.txt`.
+#[must_use]
+pub fn template_path(name: &str) -> PathBuf {
+    templates_dir().join(format!("{name}.txt"))
+}
+
+/// Directory containing the data files of a year.
+#[must_use]
+pub fn data_dir(year: Option<Year>) -> PathBuf {
+    match year {
+        Some(year) => PathBuf::from("data").join(year.to_string()),
+        None => PathBuf::from("data"),
+    }
+}
+
+/// Path of the puzzle input of a day.
+#[must_use]
+pub fn input_path(year: Option<Year>, day: Day) -> PathBuf {
+    data_dir(year).join("inputs").join(format!("{day}.txt"))
+}
+
+/// Path of the example of a day, or of the part-specific example if `part` is set.
+#[must_use]
+pub fn example_path(year: Option<Year>, day: Day, part: Option<u8>) -> PathBuf {
+    let file_name = match part {
+        Some(part) => format!("{day}-{part}.txt"),
+        None => format!("{day}.txt"),
+    };
+
+    data_dir(year).join("examples").join(file_name)
+}
+
+/// Path of the puzzle description of a day.
+#[must_use]
+pub fn puzzle_path(year: Option<Year>, day: Day) -> PathBuf {
+    data_dir(year).join("puzzles").join(format!("{day}.md"))
+}
+
+/// Path of the stored benchmarks of a year.
+#[must_use]
+pub fn timings_path(year: Option<Year>) -> PathBuf {
+    data_dir(year).join("timings.json")
+}
+
+/// Path of the accepted answers of a year.
+#[must_use]
+pub fn answers_path(year: Option<Year>) -> PathBuf {
+    data_dir(year).join("answers.json")
+}
+
+/// Path of the log of submitted answers of a year.
+#[must_use]
+pub fn submissions_path(year: Option<Year>) -> PathBuf {
+    data_dir(year).join("submissions.json")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{bin_path, example_path, input_path};
+    use crate::{day, template::Year};
+    use std::path::PathBuf;
+
+    #[test]
+    fn uses_single_year_layout_by_default() {
+        assert_eq!(bin_path(None, day!(1)), "./src/bin/01.rs");
+        assert_eq!(
+            input_path(None, day!(1)),
+            PathBuf::from("data/inputs/01.txt")
+        );
+    }
+
+    #[test]
+    fn uses_year_layout_for_years() {
+        let year = Year::new(2022);
+        assert_eq!(bin_path(year, day!(1)), "./src/bin/2022-01.rs");
+        assert_eq!(
+            example_path(year, day!(8), Some(2)),
+            PathBuf::from("data/2022/examples/08-2.txt")
+        );
+    }
+}
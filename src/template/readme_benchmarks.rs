@@ -1,6 +1,6 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fmt::Display, fs, io};
 
 use crate::template::timings::Timings;
 use crate::template::Day;
@@ -19,6 +19,15 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
@@ -53,21 +62,32 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
+    // the parse column is only shown once a solution has a shared parse step.
+    let has_parse = timings.data.iter().any(|timing| timing.parse.is_some());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    if has_parse {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
         let path = get_path_for_bin(timing.day);
+        let parse = if has_parse {
+            format!(" `{}` |", timing.parse.unwrap_or_else(|| "-".into()))
+        } else {
+            String::new()
+        };
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) |{} `{}` | `{}` |",
             timing.day.into_inner(),
             path,
+            parse,
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
         ));
@@ -108,18 +128,36 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    parse: None,
+                    parse_stats: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    parse: None,
+                    parse_stats: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    parse: None,
+                    parse_stats: None,
                     total_nanos: 9e+10,
                 },
             ],
@@ -179,4 +217,17 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_parse_step() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = Some("5ms".into());
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 195.0).unwrap();
+
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/02.rs) | `5ms` | `30ms` | `40ms` |"));
+    }
 }
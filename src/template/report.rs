@@ -0,0 +1,310 @@
+/// Machine-readable results of a solution run.
+///
+/// When the environment variable `AOC_REPORT_FILE` is set, every solution part appends one JSON record to
+/// that file. `run_multi` uses this channel to collect answers and timings from the child binaries, but it
+/// can be consumed by any other tool as well. E.g.:
+///
+/// ```json
+/// {"day":"01","part":1,"status":"solved","answer":"42","duration_nanos":74.0,"samples":100,"stats":{...}}
+/// ```
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{statistics::Statistics, Day};
+
+pub const REPORT_FILE_ENV: &str = "AOC_REPORT_FILE";
+
+/// Part number of reports for the shared parse step of a solution, see [`solution!`](crate::solution).
+pub const PARSE_STEP: u8 = 0;
+
+/// Outcome of running a single solution part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The part returned an answer, or the parse step finished.
+    Solved,
+    /// The part returned `None`.
+    Unsolved,
+    /// The part did not finish within the configured timeout.
+    Timeout,
+    /// The part panicked with the contained message.
+    Panicked(String),
+    /// The part returned an error with the contained message.
+    Error(String),
+}
+
+impl Status {
+    /// Whether the part failed to run to completion, or returned an error.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Timeout | Status::Panicked(_) | Status::Error(_)
+        )
+    }
+
+    fn tag(&self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Timeout => "timeout",
+            Status::Panicked(_) => "panicked",
+            Status::Error(_) => "error",
+        }
+    }
+
+    fn message(&self) -> Option<&str> {
+        match self {
+            Status::Panicked(message) | Status::Error(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    fn from_tag(tag: &str, message: Option<&String>) -> Result<Self, String> {
+        match tag {
+            "solved" => Ok(Status::Solved),
+            "unsolved" => Ok(Status::Unsolved),
+            "timeout" => Ok(Status::Timeout),
+            "panicked" => Ok(Status::Panicked(message.cloned().unwrap_or_default())),
+            "error" => Ok(Status::Error(message.cloned().unwrap_or_default())),
+            s => Err(format!("Unknown report status `{s}`.")),
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.message() {
+            Some(message) => write!(f, "{}: {message}", self.tag()),
+            None => f.write_str(self.tag()),
+        }
+    }
+}
+
+/// Represents the result of a single solution part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub day: Day,
+    /// The part, or [`PARSE_STEP`] for the shared parse step.
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// Timing statistics. Not present for parts that failed to run to completion.
+    pub stats: Option<Statistics>,
+}
+
+impl Report {
+    /// Appends the report to the file set via `AOC_REPORT_FILE`. Does nothing if the variable is not set.
+    pub fn emit(&self) -> Result<(), io::Error> {
+        let Ok(path) = env::var(REPORT_FILE_ENV) else {
+            return Ok(());
+        };
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", self.to_json_line())
+    }
+
+    /// Serializes the report to a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        // NOTE: stringify escapes newlines inside answers, so every record stays on one line.
+        JsonValue::from(self).stringify().unwrap()
+    }
+
+    /// Reads all reports that were written to a report file. Returns no reports if the file is not present.
+    pub fn read_all(path: &Path) -> Result<Vec<Self>, String> {
+        let Ok(s) = fs::read_to_string(path) else {
+            return Ok(vec![]);
+        };
+
+        s.lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(Report::try_from)
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Report> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Report) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.tag().into()),
+        );
+        map.insert(
+            "message".into(),
+            value
+                .status
+                .message()
+                .map_or(JsonValue::Null, |m| JsonValue::String(m.into())),
+        );
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "duration_nanos".into(),
+            value.stats.as_ref().map_or(JsonValue::Null, |stats| {
+                JsonValue::Number(stats.mean.as_nanos() as f64)
+            }),
+        );
+        map.insert(
+            "samples".into(),
+            value.stats.as_ref().map_or(JsonValue::Null, |stats| {
+                JsonValue::Number(stats.samples as f64)
+            }),
+        );
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for Report {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("Report is not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected report.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("Expected report.part to be a number.")?;
+
+        let message = json.get("message").and_then(|v| v.get::<String>());
+
+        let tag = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected report.status to be a string.")?;
+
+        let status = Status::from_tag(tag, message)?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?
+            .cloned();
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(Statistics::try_from(v)?),
+            _ => None,
+        };
+
+        Ok(Report {
+            day,
+            part,
+            status,
+            answer,
+            stats,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Report, Status};
+    use crate::{day, template::statistics::Statistics};
+    use std::time::Duration;
+
+    fn get_mock_report(answer: Option<&str>) -> Report {
+        Report {
+            day: day!(3),
+            part: 2,
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            answer: answer.map(Into::into),
+            stats: Statistics::from_samples(&[Duration::from_nanos(10), Duration::from_nanos(30)]),
+        }
+    }
+
+    #[test]
+    fn roundtrips_reports() {
+        let report = get_mock_report(Some("42"));
+        let parsed = Report::try_from(report.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn roundtrips_unsolved_reports() {
+        let report = get_mock_report(None);
+        let parsed = Report::try_from(report.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.status, Status::Unsolved);
+        assert_eq!(parsed.answer, None);
+    }
+
+    #[test]
+    fn keeps_special_characters_in_answers() {
+        let report = get_mock_report(Some("a (b @ 5 samples)\n#..#\n\"c\""));
+        let line = report.to_json_line();
+        assert_eq!(line.lines().count(), 1);
+        let parsed = Report::try_from(line.as_str()).unwrap();
+        assert_eq!(parsed.answer, report.answer);
+    }
+
+    #[test]
+    fn roundtrips_failed_reports() {
+        let mut report = get_mock_report(None);
+        report.status = Status::Panicked("index out of bounds".into());
+        report.stats = None;
+
+        let parsed = Report::try_from(report.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed, report);
+        assert_eq!(parsed.status.to_string(), "panicked: index out of bounds");
+
+        report.status = Status::Timeout;
+        let parsed = Report::try_from(report.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.status, Status::Timeout);
+
+        report.status = Status::Error("invalid digit found in string".into());
+        let parsed = Report::try_from(report.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed, report);
+        assert_eq!(
+            parsed.status.to_string(),
+            "error: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_reports() {
+        Report::try_from(r#"{ "day": "01", "part": 1 }"#).unwrap();
+    }
+}
@@ -1,40 +1,123 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    io,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
+use self::child_commands::SolutionOutput;
 use super::{
     all_days,
+    report::Report,
+    runner::{RunOptions, Solution},
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Results collected while running a set of days.
+pub struct MultiRun {
+    /// The days that were run, in day order.
+    pub days: Vec<Day>,
+    /// Days whose binary exited with an error, e.g. because it did not compile or could not read its input.
+    pub crashed: Vec<Day>,
+    /// Days whose solution ran, but did not report any part.
+    pub missing: Vec<Day>,
+    /// Reports of all parts that were run, in day order.
+    pub reports: Vec<Report>,
+    /// Timings of all days that were run. Only present for timed runs.
+    pub timings: Option<Timings>,
+}
 
-    let mut need_space = false;
+/// Options that control how a set of days is run.
+#[derive(Clone, Copy)]
+pub struct MultiRunOptions<'a> {
+    /// The year to run, `None` for the single-year layout.
+    pub year: Option<Year>,
+    pub is_release: bool,
+    pub is_timed: bool,
+    /// Number of days to run concurrently. Days run sequentially if this is `1`.
+    pub jobs: usize,
+    /// Give up on a part if its first execution takes longer than this.
+    pub timeout: Option<Duration>,
+    /// Solutions linked into this binary that can be run in-process.
+    pub solutions: &'a [Solution],
+}
+
+/// Runs a set of days in day order.
+///
+/// When running sequentially, days found in `solutions` are run in-process and all other days are run by
+/// invoking their binaries via cargo. When running with multiple jobs, every day is run in a child process
+/// and its output is buffered and printed once all days are done.
+pub fn run_multi(days_to_run: &HashSet<Day>, options: MultiRunOptions) -> MultiRun {
+    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut reports: Vec<Report> = vec![];
+    let mut crashed: Vec<Day> = vec![];
+    let mut missing: Vec<Day> = vec![];
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let mut collect = |day: Day, output: SolutionOutput| {
+        if output.crashed {
+            crashed.push(day);
+        } else if !output.skipped && output.reports.is_empty() {
+            missing.push(day);
+        }
+
+        if output.is_empty() && !output.crashed {
+            println!("Not solved.");
+        } else if output.reports.is_empty() {
+            // fall back to scraping the terminal output if the binary did not write a report.
+            timings.push(child_commands::parse_exec_time(&output.lines, day));
+        } else {
+            timings.push(child_commands::parse_reports(&output.reports, day));
+        }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        reports.extend(output.reports);
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+    if options.jobs > 1 && days.len() > 1 {
+        for (index, (day, output)) in days.iter().zip(run_parallel(&days, options)).enumerate() {
+            print_day_header(*day, index);
+            output.lines.iter().for_each(|line| println!("{line}"));
+            output.errors.iter().for_each(|line| eprintln!("{line}"));
+            collect(*day, output);
+        }
+    } else {
+        // a part that times out keeps running on its thread and would skew the timings of every later day.
+        // child processes exit once their day is done, which stops such parts.
+        let solutions = if options.is_timed && options.timeout.is_some() {
+            if !options.solutions.is_empty() {
+                println!("Note: benching with a timeout, running every day in a child process.");
             }
-        });
+            &[]
+        } else {
+            options.solutions
+        };
+
+        for (index, day) in days.iter().enumerate() {
+            print_day_header(*day, index);
+
+            let output = match solutions
+                .iter()
+                .find(|s| s.year == options.year && s.day == *day)
+            {
+                Some(solution) => run_in_process(solution, options),
+                None => child_commands::run_solution(*day, options, true).unwrap(),
+            };
+
+            collect(*day, output);
+        }
+    }
 
-    if is_timed {
+    let timings = if options.is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
@@ -43,6 +126,105 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         Some(timings)
     } else {
         None
+    };
+
+    MultiRun {
+        days,
+        crashed,
+        missing,
+        reports,
+        timings,
+    }
+}
+
+impl MultiRun {
+    /// Number of parts that failed to run to completion or returned an error, plus the number of days
+    /// whose binary exited with an error or did not report any part.
+    pub fn failures(&self) -> usize {
+        self.reports
+            .iter()
+            .filter(|r| r.status.is_failure())
+            .count()
+            + self.crashed.len()
+            + self.missing.len()
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.failures() > 0
+    }
+}
+
+fn print_day_header(day: Day, index: usize) {
+    if index > 0 {
+        println!();
+    }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+/// Runs days in child processes on `options.jobs` threads, returning their buffered output in the order of `days`.
+fn run_parallel(days: &[Day], options: MultiRunOptions) -> Vec<SolutionOutput> {
+    let jobs = options.jobs.min(days.len());
+    println!("Running {} days with {jobs} jobs...", days.len());
+
+    // build all binaries upfront, so the concurrent cargo invocations do not contend for the build lock.
+    if let Err(e) = child_commands::build_solutions(options.is_release) {
+        eprintln!("Failed to build solutions: {e}");
+    }
+
+    let next_index = AtomicUsize::new(0);
+    let outputs: Mutex<Vec<Option<SolutionOutput>>> =
+        Mutex::new(days.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = days.get(index) else {
+                    break;
+                };
+
+                let output = child_commands::run_solution(*day, options, false).unwrap();
+
+                outputs.lock().unwrap()[index] = Some(output);
+            });
+        }
+    });
+
+    outputs
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(Option::unwrap_or_default)
+        .collect()
+}
+
+/// Runs a solution that is linked into this binary.
+/// A panicking solution is reported like a crashed child process, the panic message is printed by the panic hook.
+/// The same applies to a solution that fails to read its input.
+fn run_in_process(solution: &Solution, options: MultiRunOptions) -> SolutionOutput {
+    let options = RunOptions {
+        is_timed: options.is_timed,
+        submit_part: None,
+        timeout: options.timeout,
+        year: solution.year,
+        ..RunOptions::default()
+    };
+
+    let reports = match panic::catch_unwind(AssertUnwindSafe(|| (solution.run)(&options))) {
+        Ok(Ok(reports)) => Some(reports),
+        Ok(Err(e)) => {
+            eprintln!("{e}");
+            None
+        }
+        Err(_) => None,
+    };
+
+    SolutionOutput {
+        crashed: reports.is_none(),
+        reports: reports.unwrap_or_default(),
+        ..SolutionOutput::default()
     }
 }
 
@@ -58,48 +240,116 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not capture output of child command."),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
 }
 
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{Error, MultiRunOptions};
+    use crate::template::{
+        paths,
+        report::{Report, Status, PARSE_STEP, REPORT_FILE_ENV},
+        statistics::Statistics,
+        timings::parse_duration,
+        Day,
+    };
     use std::{
+        env, fs,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        path::{Path, PathBuf},
+        process::{self, Command, Stdio},
         thread,
+        time::Duration,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// Output captured from a solution binary: the printed lines and the reports written to the side channel.
+    #[derive(Debug, Default)]
+    pub struct SolutionOutput {
+        pub lines: Vec<String>,
+        /// Lines written to stderr. Only captured when output is not streamed.
+        pub errors: Vec<String>,
+        pub reports: Vec<Report>,
+        /// Whether the binary exited with an error, e.g. because it did not compile or panicked outside of a part.
+        pub crashed: bool,
+        /// Whether the day was not run because it has not been scaffolded yet.
+        pub skipped: bool,
+    }
+
+    impl SolutionOutput {
+        pub fn is_empty(&self) -> bool {
+            self.lines.is_empty() && self.reports.is_empty()
         }
+    }
+
+    fn get_report_path(bin_name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-report-{}-{bin_name}.jsonl", process::id()))
+    }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    /// Build all solution bins.
+    pub fn build_solutions(is_release: bool) -> Result<(), Error> {
+        let mut args = vec!["build", "--quiet", "--bins"];
 
         if is_release {
             args.push("--release");
         }
 
-        if is_timed {
+        Command::new("cargo").args(&args).status()?;
+        Ok(())
+    }
+
+    /// Run the solution bin for a given day.
+    /// If `stream` is set, output is forwarded to stdout/stderr while running, otherwise it is only captured.
+    pub fn run_solution(
+        day: Day,
+        options: MultiRunOptions,
+        stream: bool,
+    ) -> Result<SolutionOutput, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Path::new(&paths::bin_path(options.year, day)).exists() {
+            return Ok(SolutionOutput {
+                skipped: true,
+                ..SolutionOutput::default()
+            });
+        }
+
+        let bin_name = paths::bin_name(options.year, day);
+        let report_path = get_report_path(&bin_name);
+        // the child appends to the report file, make sure we do not pick up stale records.
+        let _ = fs::remove_file(&report_path);
+
+        let timeout_secs = options.timeout.map(|t| t.as_secs_f64().to_string());
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
+
+        if options.is_release {
+            args.push("--release");
+        }
+
+        args.push("--");
+
+        if options.is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
+        if let Some(timeout_secs) = &timeout_secs {
+            // mirror `--timeout` flag to child invocations.
+            args.push("--timeout");
+            args.push(timeout_secs);
+        }
+
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
+            .env(REPORT_FILE_ENV, &report_path)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -110,21 +360,98 @@ pub mod child_commands {
         let mut output = vec![];
 
         let thread = thread::spawn(move || {
+            let mut errors = vec![];
+
             stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+                let line = line.unwrap();
+                if stream {
+                    eprintln!("{line}");
+                } else {
+                    errors.push(line);
+                }
             });
+
+            errors
         });
 
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
+            if stream {
+                println!("{line}");
+            }
             output.push(line);
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let errors = thread.join().unwrap();
+        let status = cmd.wait()?;
+
+        let reports = Report::read_all(&report_path).unwrap_or_else(|e| {
+            eprintln!("Could not read reports for day {day}: {e}");
+            vec![]
+        });
+        let _ = fs::remove_file(&report_path);
+
+        Ok(SolutionOutput {
+            lines: output,
+            errors,
+            reports,
+            crashed: !status.success(),
+            skipped: false,
+        })
+    }
+
+    /// Collects the timings of a day from the reports written by its binary.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn parse_reports(reports: &[Report], day: Day) -> super::Timing {
+        let mut timings = super::Timing {
+            day,
+            part_1: None,
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_error: None,
+            part_2_error: None,
+            parse: None,
+            parse_stats: None,
+            total_nanos: 0_f64,
+        };
+
+        for report in reports.iter().filter(|r| r.day == day) {
+            if report.status.is_failure() {
+                let error = Some(report.status.to_string());
+
+                match report.part {
+                    1 => timings.part_1_error = error,
+                    2 => timings.part_2_error = error,
+                    _ => {}
+                }
+
+                continue;
+            }
+
+            let Some(stats) = report
+                .stats
+                .as_ref()
+                .filter(|_| report.status == Status::Solved)
+            else {
+                continue;
+            };
+
+            let timing_str = Some(format!("{:.1?}", stats.mean));
+
+            match report.part {
+                PARSE_STEP => {
+                    (timings.parse, timings.parse_stats) = (timing_str, Some(stats.clone()))
+                }
+                1 => (timings.part_1, timings.part_1_stats) = (timing_str, Some(stats.clone())),
+                2 => (timings.part_2, timings.part_2_stats) = (timing_str, Some(stats.clone())),
+                _ => continue,
+            }
+
+            timings.total_nanos += stats.mean.as_nanos() as f64;
+        }
 
-        Ok(output)
+        timings
     }
 
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
@@ -132,9 +459,23 @@ pub mod child_commands {
             day,
             part_1: None,
             part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_error: None,
+            part_2_error: None,
+            parse: None,
+            parse_stats: None,
             total_nanos: 0_f64,
         };
 
+        for (part, error) in output.iter().filter_map(|l| parse_error(l)) {
+            match part {
+                1 => timings.part_1_error = Some(error.into()),
+                2 => timings.part_2_error = Some(error.into()),
+                _ => {}
+            }
+        }
+
         output
             .iter()
             .filter_map(|l| {
@@ -142,19 +483,25 @@ pub mod child_commands {
                     return None;
                 }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
+                let Some((timing_str, nanos, samples)) = parse_time(l) else {
                     eprintln!("Could not parse timings from line: {l}");
                     return None;
                 };
 
                 let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                let stats = find_statistics(output, part, nanos, samples);
+                Some((part, timing_str, nanos, stats))
             })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
+            .for_each(|(part, timing_str, nanos, stats)| {
+                if part.contains("Parse") {
+                    timings.parse = Some(timing_str.into());
+                    timings.parse_stats = stats;
+                } else if part.contains("Part 1") {
                     timings.part_1 = Some(timing_str.into());
+                    timings.part_1_stats = stats;
                 } else if part.contains("Part 2") {
                     timings.part_2 = Some(timing_str.into());
+                    timings.part_2_stats = stats;
                 }
 
                 timings.total_nanos += nanos;
@@ -163,29 +510,69 @@ pub mod child_commands {
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Parses lines like `Part 1: ✖ timeout` that are printed for parts that failed to run to completion.
+    fn parse_error(line: &str) -> Option<(u8, &str)> {
+        let (part, rest) = line.strip_prefix("Part ")?.split_once(": ✖ ")?;
+        let error = rest.trim();
+
+        if error.is_empty() {
+            return None;
+        }
+
+        Some((part.parse().ok()?, error))
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    fn parse_time(line: &str) -> Option<(&str, f64, u128)> {
+        let timing_and_samples = line.split(" samples)").next()?.split('(').next_back()?;
+        let mut split = timing_and_samples.split('@');
+
+        let str_timing = split.next()?.trim();
+        let samples = split.next()?.trim().parse().ok()?;
+
+        Some((str_timing, parse_duration(str_timing)?, samples))
+    }
+
+    /// Looks up the `Part N stats: ...` line for a part and combines it with the mean parsed from the result line.
+    fn find_statistics(
+        output: &[String],
+        part: &str,
+        mean_nanos: f64,
+        samples: u128,
+    ) -> Option<Statistics> {
+        let prefix = format!("{part} stats: ");
+        let line = output.iter().find_map(|l| l.strip_prefix(&prefix))?;
+
+        let mut min = None;
+        let mut median = None;
+        let mut p95 = None;
+        let mut std_dev = None;
+        let mut outliers = None;
+
+        for field in line.split(", ") {
+            match field.rsplit_once(' ')? {
+                ("min", value) => min = parse_duration(value),
+                ("median", value) => median = parse_duration(value),
+                ("p95", value) => p95 = parse_duration(value),
+                ("std dev", value) => std_dev = parse_duration(value),
+                (value, "outliers") => outliers = value.parse().ok(),
+                _ => {}
+            }
+        }
+
+        Some(Statistics {
+            samples,
+            mean: to_duration(mean_nanos),
+            min: to_duration(min?),
+            median: to_duration(median?),
+            p95: to_duration(p95?),
+            std_dev: to_duration(std_dev?),
+            outliers: outliers?,
+        })
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn to_duration(nanos: f64) -> Duration {
+        Duration::from_nanos(nanos.round() as u64)
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -204,9 +591,73 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_exec_time, parse_reports};
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                report::{Report, Status, PARSE_STEP},
+                statistics::Statistics,
+            },
+        };
+
+        #[test]
+        fn parses_reports() {
+            let stats = |nanos: &[u64]| {
+                Statistics::from_samples(
+                    &nanos
+                        .iter()
+                        .map(|x| Duration::from_nanos(*x))
+                        .collect::<Vec<_>>(),
+                )
+                .unwrap()
+            };
+
+            let res = parse_reports(
+                &[
+                    Report {
+                        day: day!(1),
+                        part: 1,
+                        status: Status::Solved,
+                        answer: Some("(1 @ 2 samples)".into()),
+                        stats: Some(stats(&[1000, 3000])),
+                    },
+                    Report {
+                        day: day!(1),
+                        part: 2,
+                        status: Status::Unsolved,
+                        answer: None,
+                        stats: Some(stats(&[5000])),
+                    },
+                ],
+                day!(1),
+            );
 
-        use crate::day;
+            assert_approx_eq!(res.total_nanos, 2000_f64);
+            assert_eq!(res.part_1.unwrap(), "2.0µs");
+            assert_eq!(res.part_1_stats.unwrap().samples, 2);
+            assert_eq!(res.part_2, None);
+            assert_eq!(res.part_2_stats, None);
+        }
+
+        #[test]
+        fn parses_parse_step_reports() {
+            let res = parse_reports(
+                &[Report {
+                    day: day!(1),
+                    part: PARSE_STEP,
+                    status: Status::Solved,
+                    answer: None,
+                    stats: Some(Statistics::from_samples(&[Duration::from_nanos(500)]).unwrap()),
+                }],
+                day!(1),
+            );
+
+            assert_approx_eq!(res.total_nanos, 500_f64);
+            assert_eq!(res.parse.unwrap(), "500.0ns");
+            assert_eq!(res.part_1, None);
+        }
 
         #[test]
         fn parses_execution_times() {
@@ -223,6 +674,24 @@ pub mod child_commands {
             assert_eq!(res.part_2.unwrap(), "74.13ms");
         }
 
+        #[test]
+        fn parses_parse_step() {
+            let res = parse_exec_time(
+                &[
+                    "Parse: ✔ (1.5µs @ 1000 samples)             ".into(),
+                    "Parse stats: min 1.0µs, median 1.5µs, p95 2.0µs, std dev 100.0ns, 3 outliers"
+                        .into(),
+                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 1574.13_f64);
+            assert_eq!(res.parse.unwrap(), "1.5µs");
+            assert_eq!(res.parse_stats.unwrap().samples, 1000);
+            assert_eq!(res.part_1.unwrap(), "74.13ns");
+        }
+
         #[test]
         fn parses_with_patterns_in_input() {
             let res = parse_exec_time(
@@ -252,5 +721,187 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_failed_parts() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: ✖ timeout             ".into(),
+                    "Part 2: ✖ panicked: attempt to divide by zero             ".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 0_f64);
+            assert_eq!(res.part_1, None);
+            assert_eq!(res.part_1_error.unwrap(), "timeout");
+            assert_eq!(
+                res.part_2_error.unwrap(),
+                "panicked: attempt to divide by zero"
+            );
+        }
+
+        #[test]
+        fn parses_errors() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 42 (1.0µs @ 10 samples)".into(),
+                    "Part 2: ✖ error: unexpected token `x` in line 3             ".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 1000_f64);
+            assert_eq!(res.part_1_error, None);
+            assert_eq!(
+                res.part_2_error.unwrap(),
+                "error: unexpected token `x` in line 3"
+            );
+        }
+
+        #[test]
+        fn parses_failed_reports() {
+            let res = parse_reports(
+                &[Report {
+                    day: day!(1),
+                    part: 2,
+                    status: Status::Timeout,
+                    answer: None,
+                    stats: None,
+                }],
+                day!(1),
+            );
+
+            assert_approx_eq!(res.total_nanos, 0_f64);
+            assert_eq!(res.part_1_error, None);
+            assert_eq!(res.part_2, None);
+            assert_eq!(res.part_2_error.unwrap(), "timeout");
+        }
+
+        #[test]
+        fn parses_statistics() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (74.1ns @ 100000 samples)".into(),
+                    "Part 1 stats: min 70.0ns, median 73.5ns, p95 80.2ns, std dev 1.5µs, 12 outliers"
+                        .into(),
+                    "Part 2: 10 (74.1ms @ 10 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+
+            let stats = res.part_1_stats.unwrap();
+            assert_eq!(stats.samples, 100000);
+            assert_eq!(stats.mean, Duration::from_nanos(74));
+            assert_eq!(stats.min, Duration::from_nanos(70));
+            assert_eq!(stats.median, Duration::from_nanos(74));
+            assert_eq!(stats.p95, Duration::from_nanos(80));
+            assert_eq!(stats.std_dev, Duration::from_nanos(1500));
+            assert_eq!(stats.outliers, 12);
+            assert_eq!(res.part_2_stats, None);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::HashSet, time::Duration};
+
+    use super::{run_multi, MultiRunOptions};
+    use crate::{
+        day,
+        template::{
+            report::{Report, Status},
+            runner::{RunOptions, Solution},
+            statistics::Statistics,
+            Year,
+        },
+    };
+
+    fn no_reports(_: &RunOptions) -> Result<Vec<Report>, String> {
+        Ok(vec![])
+    }
+
+    fn unreadable_input(_: &RunOptions) -> Result<Vec<Report>, String> {
+        Err("Could not read input from data/inputs/02.txt".into())
+    }
+
+    fn solved(_: &RunOptions) -> Result<Vec<Report>, String> {
+        Ok(vec![Report {
+            day: day!(1),
+            part: 1,
+            status: Status::Solved,
+            answer: Some("42".into()),
+            stats: Statistics::from_samples(&[Duration::from_millis(1)]),
+        }])
+    }
+
+    #[test]
+    fn counts_days_without_reports_as_failures() {
+        // no binaries exist for this year, so day 3 is skipped like a day that was not scaffolded yet.
+        let year = Year::new(2015);
+        let solutions = [
+            Solution {
+                year,
+                day: day!(1),
+                run: no_reports,
+            },
+            Solution {
+                year,
+                day: day!(2),
+                run: unreadable_input,
+            },
+        ];
+
+        let run = run_multi(
+            &[day!(1), day!(2), day!(3)].into_iter().collect(),
+            MultiRunOptions {
+                year,
+                is_release: false,
+                is_timed: false,
+                jobs: 1,
+                timeout: None,
+                solutions: &solutions,
+            },
+        );
+
+        assert_eq!(run.missing, vec![day!(1)]);
+        assert_eq!(run.crashed, vec![day!(2)]);
+        assert_eq!(run.failures(), 2);
+        assert!(run.has_failures());
+    }
+
+    #[test]
+    fn benches_in_child_processes_with_a_timeout() {
+        let year = Year::new(2015);
+        let solutions = [Solution {
+            year,
+            day: day!(1),
+            run: solved,
+        }];
+        let options = MultiRunOptions {
+            year,
+            is_release: false,
+            is_timed: true,
+            jobs: 1,
+            timeout: None,
+            solutions: &solutions,
+        };
+
+        let run = run_multi(&HashSet::from([day!(1)]), options);
+        assert_eq!(run.reports.len(), 1);
+
+        // no binary exists for this year, so the day is skipped if it does not run in-process.
+        let run = run_multi(
+            &HashSet::from([day!(1)]),
+            MultiRunOptions {
+                timeout: Some(Duration::from_secs(1)),
+                ..options
+            },
+        );
+        assert!(run.reports.is_empty());
     }
 }
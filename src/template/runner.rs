@@ -1,24 +1,427 @@
 /// Encapsulates code that interacts with solution functions.
+use std::any::Any;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{stdin, stdout, Read, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, fs, process, thread};
 
+use crate::template::answers::Answers;
+use crate::template::aoc_client::{AocClient, Backend};
+use crate::template::report::{Report, Status, PARSE_STEP};
+use crate::template::statistics::Statistics;
+use crate::template::submission::{SubmissionLog, SubmissionResult};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_cli, paths, Day, Year, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Where a solution reads its input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input, e.g. `data/inputs/NN.txt`.
+    #[default]
+    Puzzle,
+    /// An arbitrary file.
+    File(PathBuf),
+    /// Everything written to stdin.
+    Stdin,
+    /// The example, e.g. `data/examples/NN.txt`, or the part-specific example, e.g. `data/examples/NN-P.txt`.
+    Example(Option<u8>),
+}
+
+impl InputSource {
+    /// Reads the input of a day.
+    pub fn read(&self, year: Option<Year>, day: Day) -> Result<String, String> {
+        let path = match self {
+            InputSource::Puzzle => paths::input_path(year, day),
+            InputSource::Example(part) => paths::example_path(year, day, *part),
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                return stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|e| format!("Could not read input from stdin: {e}"));
+            }
+        };
+
+        fs::read_to_string(&path)
+            .map_err(|e| format!("Could not read input from {}: {e}", path.display()))
+    }
+
+    /// The command-line arguments that select this source in a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::File(path) => vec!["--input".into(), path.to_string_lossy().into()],
+            InputSource::Stdin => vec!["--stdin".into()],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(part)) => vec!["--example".into(), part.to_string()],
+        }
+    }
+
+    /// Whether a part should run against this input. Part-specific examples only apply to their part.
+    pub fn includes_part(&self, part: u8) -> bool {
+        match self {
+            InputSource::Example(Some(example_part)) => *example_part == part,
+            _ => true,
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Puzzle => write!(f, "puzzle input"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Example(None) => write!(f, "example"),
+            InputSource::Example(Some(part)) => write!(f, "example for part {part}"),
+        }
+    }
+}
+
+/// Options that control how the parts of a solution are run.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Bench every part instead of executing it once.
+    pub is_timed: bool,
+    /// Submit the result of this part via aoc-cli.
+    pub submit_part: Option<u8>,
+    /// Give up on a part if its first execution takes longer than this.
+    pub timeout: Option<Duration>,
+    /// Where to read the input from.
+    pub input: InputSource,
+    /// The year of the solution, `None` for the single-year layout.
+    pub year: Option<Year>,
+}
+
+impl RunOptions {
+    /// Read the options from the command-line arguments passed to a solution binary.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let submit_part = args.iter().position(|x| x == "--submit").map(|index| {
+            let Some(Ok(part)) = args.get(index + 1).map(|x| x.parse::<u8>()) else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+                process::exit(1);
+            };
+            part
+        });
+
+        let timeout = args.iter().position(|x| x == "--timeout").map(|index| {
+            let timeout = args
+                .get(index + 1)
+                .and_then(|x| x.parse::<f64>().ok())
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+
+            let Some(timeout) = timeout else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --timeout 10");
+                process::exit(1);
+            };
+
+            timeout
+        });
+
+        let input = if let Some(index) = args.iter().position(|x| x == "--example") {
+            InputSource::Example(args.get(index + 1).and_then(|x| x.parse().ok()))
+        } else if args.iter().any(|x| x == "--stdin") {
+            InputSource::Stdin
+        } else if let Some(index) = args.iter().position(|x| x == "--input") {
+            let Some(path) = args.get(index + 1) else {
+                eprintln!(
+                    "Unexpected command-line input. Format: cargo solve 1 --input path/to/file.txt"
+                );
+                process::exit(1);
+            };
+            InputSource::File(path.into())
+        } else {
+            InputSource::Puzzle
+        };
+
+        if submit_part.is_some() && input != InputSource::Puzzle {
+            eprintln!("Solutions can only be submitted when running against the puzzle input.");
+            process::exit(1);
+        }
+
+        RunOptions {
+            is_timed: args.iter().any(|x| x == "--time"),
+            submit_part,
+            timeout,
+            input,
+            // NOTE: solution binaries know their year from their name, see `solution!`.
+            year: None,
+        }
+    }
+}
+
+/// A solution that is linked into the main binary and can be run in-process.
+/// Entries are generated by the [`solution!`](crate::solution) macro.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: Option<Year>,
+    pub day: Day,
+    /// Runs the solution, returns an error if its input could not be read.
+    pub run: fn(&RunOptions) -> Result<Vec<Report>, String>,
+}
+
+/// Types that can be the answer of a solution part.
+///
+/// Implemented for numbers, strings and chars. Custom types that should be printed via [`Display`] can
+/// implement it as well, e.g. `impl advent_of_code::template::runner::Answer for Grid {}`.
+pub trait Answer: Display {}
+
+macro_rules! impl_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {})*
+    };
+}
+
+impl_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_answer!(String, &str, char);
+
+/// Return types of solution parts: `Option<T>`, `Result<T, E>` and `Option<Result<T, E>>`.
+pub trait PartResult {
+    type Answer: Answer;
+
+    /// Returns the answer, [`None`] for unsolved parts, or the message of the error returned by the part.
+    fn answer(&self) -> Result<Option<&Self::Answer>, String>;
+}
+
+impl<T: Answer> PartResult for Option<T> {
+    type Answer = T;
+
+    fn answer(&self) -> Result<Option<&T>, String> {
+        Ok(self.as_ref())
+    }
+}
+
+impl<T: Answer, E: Display> PartResult for Result<T, E> {
+    type Answer = T;
+
+    fn answer(&self) -> Result<Option<&T>, String> {
+        match self {
+            Ok(answer) => Ok(Some(answer)),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+impl<T: Answer, E: Display> PartResult for Option<Result<T, E>> {
+    type Answer = T;
+
+    fn answer(&self) -> Result<Option<&T>, String> {
+        match self {
+            Some(result) => result.answer(),
+            None => Ok(None),
+        }
+    }
+}
+
+pub fn run_part<I, R, F>(func: F, input: I, day: Day, part: u8, options: &RunOptions) -> Report
+where
+    I: Clone + Send + 'static,
+    R: PartResult + Send + 'static,
+    F: Fn(I) -> R + Send + 'static,
+{
     let part_str = format!("Part {part}");
+    let hook_part_str = part_str.clone();
+
+    let run = run_isolated(func, input, options, move |result: &R| {
+        // errors are printed once the part is done, see below.
+        print_result(&result.answer().unwrap_or(None), &hook_part_str, "");
+    });
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (output, stats) = match run {
+        Ok(run) => run,
+        Err(status) => return report_failure(day, part, status),
+    };
+
+    let result = match output.answer() {
+        Ok(result) => result,
+        Err(message) => return report_failure(day, part, Status::Error(message)),
+    };
+
+    print_result(&result, &part_str, &format_duration(&stats));
+
+    if result.is_some() && stats.samples > 1 {
+        println!("{}", format_statistics(&stats, &part_str));
+    }
+
+    let report = Report {
+        day,
+        part,
+        status: if result.is_some() {
+            Status::Solved
+        } else {
+            Status::Unsolved
+        },
+        answer: result.as_ref().map(ToString::to_string),
+        stats: Some(stats),
+    };
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    if let Err(e) = report.emit() {
+        eprintln!("Failed to write report: {e}");
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        if options.submit_part == Some(part) {
+            let answer = result.to_string();
+            let mut log = SubmissionLog::read_from_file(options.year);
+
+            if let Err(refusal) = log.check(day, part, &answer) {
+                println!("{part_str}: not submitted, {refusal}");
+            } else {
+                match submit_result(&answer, options.year, day, part) {
+                    Ok(submission) => {
+                        println!("{part_str}: {submission}");
+
+                        if submission == SubmissionResult::Correct {
+                            record_answer(&answer, options.year, day, part);
+                        }
+
+                        log.record(day, part, &answer, submission);
+
+                        if let Err(e) = log.store_file(options.year) {
+                            eprintln!("Failed to log the submission: {e}");
+                        }
+                    }
+                    Err(e) => eprintln!("failed to submit result: {e}"),
+                }
+            }
+        }
+    }
+
+    report
+}
+
+/// Run the shared parse step of a solution and return its output, which is passed to every part.
+/// If parsing fails to run to completion, all `parts` are reported as failed with the same status.
+pub fn run_parse<P, F>(
+    func: F,
+    input: &'static str,
+    day: Day,
+    parts: &[u8],
+    options: &RunOptions,
+) -> Result<&'static P, Vec<Report>>
+where
+    P: Send + Sync + 'static,
+    F: Fn(&'static str) -> P + Send + 'static,
+{
+    let part_str = "Parse";
+
+    let run = run_isolated(func, input, options, move |_| {
+        print!("{part_str}: ✔");
+    });
+
+    let (parsed, stats) = match run {
+        Ok(run) => run,
+        Err(status) => {
+            print!("\r");
+            println!("{part_str}: ✖ {status}             ");
+
+            return Err(parts
+                .iter()
+                .filter(|part| options.input.includes_part(**part))
+                .map(|part| report_failure(day, *part, status.clone()))
+                .collect());
+        }
+    };
+
+    print!("\r");
+    println!("{part_str}: ✔{}             ", format_duration(&stats));
+
+    if stats.samples > 1 {
+        println!("{}", format_statistics(&stats, part_str));
+    }
+
+    let report = Report {
+        day,
+        part: PARSE_STEP,
+        status: Status::Solved,
+        answer: None,
+        stats: Some(stats),
+    };
+
+    if let Err(e) = report.emit() {
+        eprintln!("Failed to write report: {e}");
+    }
+
+    // parts run on their own threads, which requires the parsed input to live for the rest of the program.
+    Ok(Box::leak(Box::new(parsed)))
+}
+
+/// Print and report a part that failed to run to completion.
+fn report_failure(day: Day, part: u8, status: Status) -> Report {
+    print!("\r");
+    println!("Part {part}: ✖ {status}             ");
+
+    let report = Report {
+        day,
+        part,
+        status,
+        answer: None,
+        stats: None,
+    };
+
+    if let Err(e) = report.emit() {
+        eprintln!("Failed to write report: {e}");
+    }
+
+    report
+}
+
+/// Run a solution part on a separate thread, so a panicking or hanging part is reported instead of aborting the run.
+/// The hook receives the result of the first execution, before the part is benched.
+/// NOTE: a part that times out can not be stopped and keeps running in the background until the process exits.
+fn run_isolated<I, T, F>(
+    func: F,
+    input: I,
+    options: &RunOptions,
+    hook: impl Fn(&T) + Send + 'static,
+) -> Result<(T, Statistics), Status>
+where
+    I: Clone + Send + 'static,
+    T: Send + 'static,
+    F: Fn(I) -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let is_timed = options.is_timed;
+
+    let handle = thread::spawn(move || {
+        run_timed(func, input, is_timed, |result| {
+            // the receiver is gone if the part already timed out, stay silent and skip benching then.
+            if sender.send(()).is_err() {
+                return false;
+            }
+
+            hook(result);
+            true
+        })
+    });
+
+    let first_run = match options.timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+
+    match first_run {
+        Err(RecvTimeoutError::Timeout) => Err(Status::Timeout),
+        // either the first execution finished, or the thread panicked and dropped the sender.
+        _ => handle
+            .join()
+            .map_err(|payload| Status::Panicked(panic_message(payload.as_ref()))),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".into()
     }
 }
 
@@ -28,8 +431,9 @@ pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, d
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    is_timed: bool,
+    hook: impl Fn(&T) -> bool,
+) -> (T, Statistics) {
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -41,32 +445,27 @@ fn run_timed<I: Clone, T>(
     };
     let base_time = timer.elapsed();
 
-    hook(&result);
-
-    let run = if std::env::args().any(|x| x == "--time") {
+    // the hook decides whether the run is still of interest.
+    let samples = if hook(&result) && is_timed {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        vec![base_time]
     };
 
-    (result, run.0, run.1)
+    // NOTE: `samples` always contains at least one value.
+    (result, Statistics::from_samples(&samples).unwrap())
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> Vec<Duration> {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / base_time.as_nanos().max(10)).clamp(10, 10000);
 
-    let mut timers: Vec<Duration> = vec![];
+    let mut timers: Vec<Duration> = Vec::with_capacity(bench_iterations as usize);
 
     for _ in 0..bench_iterations {
         // need a clone here to make the borrow checker happy.
@@ -76,29 +475,35 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    timers
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+fn format_duration(stats: &Statistics) -> String {
+    let Statistics { mean, samples, .. } = stats;
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+    if *samples == 1 {
+        format!(" ({mean:.1?})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(" ({mean:.1?} @ {samples} samples)")
     }
 }
 
+/// Formats the sample distribution of a benched part. E.g. `Part 1 stats: min 1.0µs, median 1.2µs, p95 1.9µs, std dev 210.0ns, 3 outliers`
+fn format_statistics(stats: &Statistics, part: &str) -> String {
+    let Statistics {
+        min,
+        median,
+        p95,
+        std_dev,
+        outliers,
+        ..
+    } = stats;
+
+    format!(
+        "{part} stats: min {min:.1?}, median {median:.1?}, p95 {p95:.1?}, std dev {std_dev:.1?}, {outliers} outliers"
+    )
+}
+
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
@@ -134,41 +539,41 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
+/// Submit one part of the solution and evaluate the response.
+fn submit_result(
+    answer: &str,
+    year: Option<Year>,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
-
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    }
+) -> Result<SubmissionResult, String> {
+    let message = if Backend::from_env() == Backend::AocCli {
+        if aoc_cli::check().is_err() {
+            eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+            process::exit(1);
+        }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+        println!("Submitting result via aoc-cli...");
+        let output = aoc_cli::submit(year, day, part, answer).map_err(|e| e.to_string())?;
+        String::from_utf8_lossy(&output.stdout).to_string()
+    } else {
+        let client = AocClient::new(year).map_err(|e| e.to_string())?;
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+        println!("Submitting result...");
+        client
+            .submit(day, part, answer)
+            .map_err(|e| e.to_string())?
     };
 
-    if part_submit != part {
-        return None;
-    }
+    SubmissionResult::parse(&message).ok_or_else(|| format!("unexpected response: {message}"))
+}
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+/// Stores a correct answer, so `cargo verify` can check it later on.
+fn record_answer(answer: &str, year: Option<Year>, day: Day, part: u8) {
+    let mut answers = Answers::read_from_file(year);
+    answers.set(day, part, answer);
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    match answers.store_file(year) {
+        Ok(()) => println!("Recorded the answer for `cargo verify`."),
+        Err(e) => eprintln!("Failed to record the answer: {e}"),
+    }
 }
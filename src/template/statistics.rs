@@ -0,0 +1,161 @@
+/// Summary statistics over the samples collected while benching a solution part.
+use std::{collections::HashMap, time::Duration};
+use tinyjson::JsonValue;
+
+/// Describes the distribution of execution times of a benched solution part.
+/// Outliers are counted with Tukey's fences, i.e. samples outside of `1.5 * IQR` of the quartiles.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Statistics {
+    pub samples: u128,
+    pub mean: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+    pub outliers: usize,
+}
+
+impl Statistics {
+    /// Computes statistics for a set of samples. Returns `None` if no samples were provided.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let count = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / count;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count;
+
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (lower_fence, upper_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        Some(Statistics {
+            samples: nanos.len() as u128,
+            mean: from_nanos(mean),
+            min: from_nanos(nanos[0]),
+            median: from_nanos(percentile(&nanos, 0.5)),
+            p95: from_nanos(percentile(&nanos, 0.95)),
+            std_dev: from_nanos(variance.sqrt()),
+            outliers: nanos
+                .iter()
+                .filter(|x| **x < lower_fence || **x > upper_fence)
+                .count(),
+        })
+    }
+}
+
+/// Linearly interpolated percentile of an ascending, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Statistics> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Statistics) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("mean_nanos".into(), nanos(value.mean));
+        map.insert("min_nanos".into(), nanos(value.min));
+        map.insert("median_nanos".into(), nanos(value.median));
+        map.insert("p95_nanos".into(), nanos(value.p95));
+        map.insert("std_dev_nanos".into(), nanos(value.std_dev));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Statistics {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing statistics to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected timing statistics.{key} to be a number."))
+        };
+
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        Ok(Statistics {
+            samples: number("samples")? as u128,
+            mean: duration("mean_nanos")?,
+            min: duration("min_nanos")?,
+            median: duration("median_nanos")?,
+            p95: duration("p95_nanos")?,
+            std_dev: duration("std_dev_nanos")?,
+            outliers: number("outliers")? as usize,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Statistics;
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(Statistics::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = Statistics::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(42));
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn computes_distribution() {
+        let stats = Statistics::from_samples(&nanos(&[40, 10, 30, 20])).unwrap();
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.mean, Duration::from_nanos(25));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(25));
+        assert_eq!(stats.p95, Duration::from_nanos(39));
+        assert_eq!(stats.std_dev, Duration::from_nanos(11));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn counts_outliers() {
+        let stats = Statistics::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 500])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.median, Duration::from_nanos(11));
+    }
+}
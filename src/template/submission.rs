@@ -0,0 +1,467 @@
+/// Outcomes of submitting answers to Advent of Code, and a local log of past submissions.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{paths, Day, Year};
+
+/// Result of submitting an answer, parsed from the message on the response page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmissionResult {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint whether it is too high or too low.
+    Wrong,
+    /// An answer was submitted too recently. Contains the remaining wait time, if the page states it.
+    RateLimited(Option<Duration>),
+    /// The part was already solved, the answer was not checked.
+    AlreadySolved,
+}
+
+impl SubmissionResult {
+    /// Parses the message returned by Advent of Code (or printed by aoc-cli) after submitting an answer.
+    pub fn parse(message: &str) -> Option<Self> {
+        // aoc-cli wraps the message to the terminal width.
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+        let message = message.as_str();
+
+        if message.contains("That's the right answer") {
+            Some(SubmissionResult::Correct)
+        } else if message.contains("That's not the right answer") {
+            Some(if message.contains("too high") {
+                SubmissionResult::TooHigh
+            } else if message.contains("too low") {
+                SubmissionResult::TooLow
+            } else {
+                SubmissionResult::Wrong
+            })
+        } else if message.contains("You gave an answer too recently") {
+            Some(SubmissionResult::RateLimited(parse_wait_time(message)))
+        } else if message.contains("Did you already complete it") {
+            Some(SubmissionResult::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for SubmissionResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionResult::Correct => write!(f, "✔ correct answer"),
+            SubmissionResult::TooHigh => write!(f, "✖ wrong answer, too high"),
+            SubmissionResult::TooLow => write!(f, "✖ wrong answer, too low"),
+            SubmissionResult::Wrong => write!(f, "✖ wrong answer"),
+            SubmissionResult::RateLimited(Some(wait)) => {
+                write!(f, "⏳ rate limited, try again in {}s", wait.as_secs())
+            }
+            SubmissionResult::RateLimited(None) => write!(f, "⏳ rate limited, try again later"),
+            SubmissionResult::AlreadySolved => write!(f, "✔ part is already solved"),
+        }
+    }
+}
+
+impl SubmissionResult {
+    /// Whether the answer was checked, i.e. the result says something about the answer.
+    fn is_verdict(&self) -> bool {
+        !matches!(
+            self,
+            SubmissionResult::RateLimited(_) | SubmissionResult::AlreadySolved
+        )
+    }
+
+    fn tag(&self) -> &'static str {
+        match self {
+            SubmissionResult::Correct => "correct",
+            SubmissionResult::TooHigh => "too_high",
+            SubmissionResult::TooLow => "too_low",
+            SubmissionResult::Wrong => "wrong",
+            SubmissionResult::RateLimited(_) => "rate_limited",
+            SubmissionResult::AlreadySolved => "already_solved",
+        }
+    }
+
+    fn from_tag(tag: &str) -> Result<Self, String> {
+        match tag {
+            "correct" => Ok(SubmissionResult::Correct),
+            "too_high" => Ok(SubmissionResult::TooHigh),
+            "too_low" => Ok(SubmissionResult::TooLow),
+            "wrong" => Ok(SubmissionResult::Wrong),
+            "rate_limited" => Ok(SubmissionResult::RateLimited(None)),
+            "already_solved" => Ok(SubmissionResult::AlreadySolved),
+            s => Err(format!("Unknown submission result `{s}`.")),
+        }
+    }
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub result: SubmissionResult,
+}
+
+/// Reason for not submitting an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved with the contained answer.
+    Solved(String),
+    /// The same answer was submitted before.
+    Duplicate(SubmissionResult),
+    /// The answer is not higher than an answer that was too low.
+    NotAbove(String),
+    /// The answer is not lower than an answer that was too high.
+    NotBelow(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved(answer) => write!(f, "part was already solved with `{answer}`"),
+            Refusal::Duplicate(result) => {
+                write!(f, "answer was submitted before ({result})")
+            }
+            Refusal::NotAbove(bound) => {
+                write!(f, "answer must be higher than `{bound}`, which is too low")
+            }
+            Refusal::NotBelow(bound) => {
+                write!(f, "answer must be lower than `{bound}`, which is too high")
+            }
+        }
+    }
+}
+
+/// Log of all submitted answers. Used to refuse submissions that are known to be wrong.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct SubmissionLog {
+    pub data: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Dehydrate the log to a JSON file.
+    pub fn store_file(&self, year: Option<Year>) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(paths::submissions_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the log from a JSON file. If not present, returns an empty log.
+    pub fn read_from_file(year: Option<Year>) -> Self {
+        let s = match fs::read_to_string(paths::submissions_path(year)) {
+            Err(e) if e.kind() == ErrorKind::NotFound => return SubmissionLog::default(),
+            s => s
+                .map_err(|x| x.to_string())
+                .and_then(SubmissionLog::try_from),
+        };
+
+        match s {
+            Ok(log) => log,
+            Err(e) => {
+                eprintln!("{e}");
+                SubmissionLog::default()
+            }
+        }
+    }
+
+    /// Appends a submission to the log.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, result: SubmissionResult) {
+        self.data.push(Submission {
+            day,
+            part,
+            answer: answer.into(),
+            result,
+        });
+    }
+
+    /// Checks an answer against the previous submissions of a part.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), Refusal> {
+        let submissions = self
+            .data
+            .iter()
+            .filter(|s| s.day == day && s.part == part && s.result.is_verdict());
+
+        for submission in submissions {
+            if submission.result == SubmissionResult::Correct {
+                return Err(Refusal::Solved(submission.answer.clone()));
+            }
+
+            if submission.answer == answer {
+                return Err(Refusal::Duplicate(submission.result.clone()));
+            }
+
+            // bounds only apply to numeric answers.
+            let (Ok(value), Ok(bound)) =
+                (answer.parse::<i128>(), submission.answer.parse::<i128>())
+            else {
+                continue;
+            };
+
+            match submission.result {
+                SubmissionResult::TooLow if value <= bound => {
+                    return Err(Refusal::NotAbove(submission.answer.clone()));
+                }
+                SubmissionResult::TooHigh if value >= bound => {
+                    return Err(Refusal::NotBelow(submission.answer.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses the wait time from e.g. `You have 1m 12s left to wait.`
+fn parse_wait_time(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<SubmissionLog> for JsonValue {
+    fn from(value: SubmissionLog) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for SubmissionLog {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(SubmissionLog {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "result".into(),
+            JsonValue::String(value.result.tag().into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let result = json
+            .get("result")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.result to be a string.")?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            result: SubmissionResult::from_tag(result)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Refusal, SubmissionLog, SubmissionResult};
+    use crate::day;
+    use std::time::Duration;
+
+    fn get_mock_log() -> SubmissionLog {
+        let mut log = SubmissionLog::default();
+        log.record(day!(1), 1, "100", SubmissionResult::TooLow);
+        log.record(day!(1), 1, "500", SubmissionResult::TooHigh);
+        log.record(day!(1), 1, "abc", SubmissionResult::Wrong);
+        log.record(day!(1), 1, "300", SubmissionResult::RateLimited(None));
+        log.record(day!(2), 1, "42", SubmissionResult::Correct);
+        log
+    }
+
+    #[test]
+    fn parses_correct_answers() {
+        let message =
+            "That's the right answer! You are one gold star closer to restoring snow operations.";
+        assert_eq!(
+            SubmissionResult::parse(message),
+            Some(SubmissionResult::Correct)
+        );
+    }
+
+    #[test]
+    fn parses_wrong_answers() {
+        let high = "That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data.";
+        let low = "That's not the right answer; your answer is too low.";
+        let wrong = "That's not the right answer. If you're stuck, make sure you're using the full input data.";
+
+        assert_eq!(
+            SubmissionResult::parse(high),
+            Some(SubmissionResult::TooHigh)
+        );
+        assert_eq!(SubmissionResult::parse(low), Some(SubmissionResult::TooLow));
+        assert_eq!(
+            SubmissionResult::parse(wrong),
+            Some(SubmissionResult::Wrong)
+        );
+    }
+
+    #[test]
+    fn parses_rate_limits() {
+        let message = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 12s left to wait. [[Return to Day 1]](/2023/day/1)";
+        assert_eq!(
+            SubmissionResult::parse(message),
+            Some(SubmissionResult::RateLimited(Some(Duration::from_secs(72))))
+        );
+    }
+
+    #[test]
+    fn parses_solved_parts() {
+        let message = "You don't seem to be solving the right level.  Did you already complete it? [[Return to Day 1]](/2023/day/1)";
+        assert_eq!(
+            SubmissionResult::parse(message),
+            Some(SubmissionResult::AlreadySolved)
+        );
+    }
+
+    #[test]
+    fn parses_wrapped_messages() {
+        let message = "That's not the right answer; your answer is too\nlow.";
+        assert_eq!(
+            SubmissionResult::parse(message),
+            Some(SubmissionResult::TooLow)
+        );
+    }
+
+    #[test]
+    fn ignores_unknown_messages() {
+        assert_eq!(SubmissionResult::parse("<html></html>"), None);
+    }
+
+    #[test]
+    fn allows_answers_within_bounds() {
+        let log = get_mock_log();
+        assert_eq!(log.check(day!(1), 1, "200"), Ok(()));
+        assert_eq!(log.check(day!(1), 1, "300"), Ok(()));
+        assert_eq!(log.check(day!(1), 2, "100"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_duplicate_answers() {
+        let log = get_mock_log();
+        assert_eq!(
+            log.check(day!(1), 1, "abc"),
+            Err(Refusal::Duplicate(SubmissionResult::Wrong))
+        );
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let log = get_mock_log();
+        assert_eq!(
+            log.check(day!(1), 1, "100"),
+            Err(Refusal::Duplicate(SubmissionResult::TooLow))
+        );
+        assert_eq!(
+            log.check(day!(1), 1, "99"),
+            Err(Refusal::NotAbove("100".into()))
+        );
+        assert_eq!(
+            log.check(day!(1), 1, "501"),
+            Err(Refusal::NotBelow("500".into()))
+        );
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let log = get_mock_log();
+        assert_eq!(
+            log.check(day!(2), 1, "43"),
+            Err(Refusal::Solved("42".into()))
+        );
+    }
+
+    #[test]
+    fn roundtrips_logs() {
+        let log = get_mock_log();
+        let json = tinyjson::JsonValue::from(log.clone()).stringify().unwrap();
+        assert_eq!(SubmissionLog::try_from(json).unwrap().data, log.data);
+    }
+}
@@ -1,9 +1,7 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
-
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+use crate::template::{paths, statistics::Statistics, Day, Year};
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -11,6 +9,16 @@ pub struct Timing {
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<Statistics>,
+    pub part_2_stats: Option<Statistics>,
+    /// Describes why part 1 failed to run to completion, e.g. `timeout`.
+    pub part_1_error: Option<String>,
+    /// Describes why part 2 failed to run to completion, e.g. `timeout`.
+    pub part_2_error: Option<String>,
+    /// Execution time of the shared parse step, if the solution has one.
+    pub parse: Option<String>,
+    pub parse_stats: Option<Statistics>,
+    /// Sum of the execution times of the parse step and both parts.
     pub total_nanos: f64,
 }
 
@@ -23,15 +31,15 @@ pub struct Timings {
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Option<Year>) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(paths::timings_path(year))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        let s = fs::read_to_string(TIMINGS_FILE_PATH)
+    pub fn read_from_file(year: Option<Year>) -> Self {
+        let s = fs::read_to_string(paths::timings_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from);
 
@@ -58,7 +66,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|timing| timing.day);
         Timings { data }
     }
 
@@ -72,6 +80,100 @@ impl Timings {
             .iter()
             .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
     }
+
+    /// Whether a timing of any part of `day` is stored.
+    pub fn has_day(&self, day: Day) -> bool {
+        self.data
+            .iter()
+            .any(|t| t.day == day && (t.part_nanos(1).is_some() || t.part_nanos(2).is_some()))
+    }
+
+    /// Compare `new` timings against `self`, yielding a delta for every part present in both.
+    pub fn compare(&self, new: &Self) -> Vec<TimingDelta> {
+        let mut deltas = vec![];
+
+        for timing in &new.data {
+            let Some(stored) = self.data.iter().find(|t| t.day == timing.day) else {
+                continue;
+            };
+
+            for part in 1..=2 {
+                if let (Some(before_nanos), Some(after_nanos)) =
+                    (stored.part_nanos(part), timing.part_nanos(part))
+                {
+                    deltas.push(TimingDelta {
+                        day: timing.day,
+                        part,
+                        before_nanos,
+                        after_nanos,
+                    });
+                }
+            }
+        }
+
+        deltas.sort_unstable_by_key(|delta| (delta.day, delta.part));
+        deltas
+    }
+}
+
+impl Timing {
+    /// Mean execution time of a part in nanoseconds.
+    /// Falls back to the formatted timing for timings stored without statistics.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        let (timing_str, stats) = match part {
+            1 => (&self.part_1, &self.part_1_stats),
+            2 => (&self.part_2, &self.part_2_stats),
+            _ => return None,
+        };
+
+        match stats {
+            Some(stats) => Some(stats.mean.as_nanos() as f64),
+            None => parse_duration(timing_str.as_ref()?),
+        }
+    }
+}
+
+/// Change in execution time of a single part between two sets of timings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingDelta {
+    pub day: Day,
+    pub part: u8,
+    pub before_nanos: f64,
+    pub after_nanos: f64,
+}
+
+impl TimingDelta {
+    pub fn delta_nanos(&self) -> f64 {
+        self.after_nanos - self.before_nanos
+    }
+
+    pub fn delta_percent(&self) -> f64 {
+        if self.before_nanos == 0.0 {
+            return 0.0;
+        }
+        self.delta_nanos() / self.before_nanos * 100.0
+    }
+
+    /// Whether the part got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.delta_percent() > threshold
+    }
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
+/// Parses a duration formatted via `Debug` (e.g. `74.1ns` or `1.2ms`) into nanoseconds.
+pub fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s {
+        s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -140,6 +242,54 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "part_1_stats".into(),
+            value
+                .part_1_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value
+                .part_2_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "parse_stats".into(),
+            value
+                .parse_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_1_error".into(),
+            value
+                .part_1_error
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "part_2_error".into(),
+            value
+                .part_2_error
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -173,10 +323,37 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: statistics are optional to stay compatible with timings stored by earlier versions.
+        let part_1_stats = match json.get("part_1_stats") {
+            Some(v) if !v.is_null() => Some(Statistics::try_from(v)?),
+            _ => None,
+        };
+
+        let part_2_stats = match json.get("part_2_stats") {
+            Some(v) if !v.is_null() => Some(Statistics::try_from(v)?),
+            _ => None,
+        };
+
+        let parse = json.get("parse").and_then(|v| v.get::<String>());
+
+        let parse_stats = match json.get("parse_stats") {
+            Some(v) if !v.is_null() => Some(Statistics::try_from(v)?),
+            _ => None,
+        };
+
+        let part_1_error = json.get("part_1_error").and_then(|v| v.get::<String>());
+        let part_2_error = json.get("part_2_error").and_then(|v| v.get::<String>());
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats,
+            part_2_stats,
+            part_1_error: part_1_error.cloned(),
+            part_2_error: part_2_error.cloned(),
+            parse: parse.cloned(),
+            parse_stats,
             total_nanos,
         })
     }
@@ -197,18 +374,36 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    parse: None,
+                    parse_stats: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    parse: None,
+                    parse_stats: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    parse: None,
+                    parse_stats: None,
                     total_nanos: 4e+10,
                 },
             ],
@@ -217,6 +412,7 @@ mod tests {
 
     mod deserialization {
         use crate::{day, template::timings::Timings};
+        use std::time::Duration;
 
         #[test]
         fn handles_json_timings() {
@@ -230,6 +426,28 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_statistics() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "samples": 10, "mean_nanos": 1000000, "min_nanos": 900000, "median_nanos": 950000, "p95_nanos": 1200000, "std_dev_nanos": 5000, "outliers": 1 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.samples, 10);
+            assert_eq!(stats.min, Duration::from_nanos(900_000));
+            assert_eq!(stats.p95, Duration::from_nanos(1_200_000));
+            assert_eq!(stats.outliers, 1);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn handles_json_errors() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": null, "part_2": "1ms", "total_nanos": 1000000, "part_1_error": "timeout" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_error, Some("timeout".to_string()));
+            assert_eq!(timing.part_2_error, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -288,11 +506,17 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    parse: None,
+                    parse_stats: None,
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -302,11 +526,17 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    parse: None,
+                    parse_stats: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -316,11 +546,78 @@ mod tests {
                     day: day!(1),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    parse: None,
+                    parse_stats: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod compare {
+        use crate::{
+            day,
+            template::timings::{Timing, Timings},
+        };
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn compares_overlapping_parts() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![
+                    Timing {
+                        day: day!(2),
+                        part_1: Some("45ms".into()),
+                        part_2: Some("20ms".into()),
+                        part_1_stats: None,
+                        part_2_stats: None,
+                        part_1_error: None,
+                        part_2_error: None,
+                        parse: None,
+                        parse_stats: None,
+                        total_nanos: 6.5e+7,
+                    },
+                    Timing {
+                        day: day!(3),
+                        part_1: Some("1ms".into()),
+                        part_2: None,
+                        part_1_stats: None,
+                        part_2_stats: None,
+                        part_1_error: None,
+                        part_2_error: None,
+                        parse: None,
+                        parse_stats: None,
+                        total_nanos: 1e+6,
+                    },
+                ],
+            };
+
+            let deltas = timings.compare(&other);
+            assert_eq!(deltas.len(), 2);
+
+            assert_eq!(deltas[0].day, day!(2));
+            assert_eq!(deltas[0].part, 1);
+            assert_eq!(deltas[0].delta_nanos(), 1.5e+7);
+            assert_eq!(deltas[0].delta_percent(), 50.0);
+            assert_eq!(deltas[0].is_regression(10.0), true);
+
+            assert_eq!(deltas[1].part, 2);
+            assert_eq!(deltas[1].delta_percent(), -50.0);
+            assert_eq!(deltas[1].is_regression(10.0), false);
+        }
+
+        #[test]
+        fn handles_empty_timings() {
+            let timings = Timings::default();
+            assert_eq!(timings.compare(&get_mock_timings()).len(), 0);
         }
     }
 
@@ -340,6 +637,12 @@ mod tests {
                     day: day!(3),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    parse: None,
+                    parse_stats: None,
                     total_nanos: 0_f64,
                 }],
             };
@@ -360,6 +663,12 @@ mod tests {
                     day: day!(2),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    parse: None,
+                    parse_stats: None,
                     total_nanos: 0_f64,
                 }],
             };
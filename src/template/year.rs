@@ -0,0 +1,118 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A valid year of advent (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2022).unwrap();
+/// assert_eq!(year.to_string(), "2022")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < 2015 || year > 9999 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the default year configured via `AOC_YEAR`, if any.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Extracts the year from the name of a solution binary, e.g. `2022-01`.
+    /// Returns [`None`] for binaries of the default layout, e.g. `01`.
+    pub const fn from_bin_name(name: &str) -> Option<Self> {
+        let bytes = name.as_bytes();
+
+        if bytes.len() != 7 || bytes[4] != b'-' {
+            return None;
+        }
+
+        let mut year = 0;
+        let mut i = 0;
+
+        while i < 4 {
+            if !bytes[i].is_ascii_digit() {
+                return None;
+            }
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        Self::new(year)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2022".parse::<Year>().unwrap(), Year(2022));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("22".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn parses_bin_names() {
+        assert_eq!(Year::from_bin_name("2022-01"), Some(Year(2022)));
+        assert_eq!(Year::from_bin_name("01"), None);
+        assert_eq!(Year::from_bin_name("advent_of_code"), None);
+        assert_eq!(Year::from_bin_name("20a2-01"), None);
+    }
+}
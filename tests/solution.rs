@@ -0,0 +1,29 @@
+use std::{env, fs, process::Command};
+
+#[test]
+fn exits_with_an_error_if_the_input_is_missing() {
+    // solutions read their input relative to the working directory, which has no `data` directory here.
+    let dir = env::temp_dir().join(format!("aoc-missing-input-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_01"))
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+
+    let _ = fs::remove_dir_all(&dir);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Could not read input"));
+}
+
+#[test]
+fn exits_with_an_error_if_the_input_file_is_unreadable() {
+    let output = Command::new(env!("CARGO_BIN_EXE_01"))
+        .args(["--input", "does/not/exist.txt"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("does/not/exist.txt"));
+}